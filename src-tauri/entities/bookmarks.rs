@@ -1,6 +1,8 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::sea_orm_active_enums::TargetType;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "bookmarks")]
 pub struct Model {
@@ -8,7 +10,7 @@ pub struct Model {
     pub id: i32,
     pub user_id: i32,
     pub target_id: i32,
-    pub target_type: String,
+    pub target_type: TargetType,
     pub created_at: DateTimeUtc,
     pub title: String,
     pub note: Option<String>,
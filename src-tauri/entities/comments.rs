@@ -1,6 +1,8 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::sea_orm_active_enums::TargetType;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "comments")]
 pub struct Model {
@@ -12,11 +14,29 @@ pub struct Model {
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
     pub target_id: i32,
-    pub target_type: String,
+    pub target_type: TargetType,
+    pub parent_comment_id: Option<i32>,
+    pub depth: i32,
+    pub hidden_at: Option<DateTimeUtc>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub rendered_html: Option<String>,
+    pub render_version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::comment_mentions::Entity")]
+    CommentMentions,
+    #[sea_orm(has_many = "super::comment_votes::Entity")]
+    CommentVotes,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentCommentId",
+        to = "Column::Id",
+        on_update = "Restrict",
+        on_delete = "Cascade"
+    )]
+    SelfRef,
     #[sea_orm(
         belongs_to = "super::users::Entity",
         from = "Column::UserId",
@@ -27,6 +47,18 @@ pub enum Relation {
     Users,
 }
 
+impl Related<super::comment_mentions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentMentions.def()
+    }
+}
+
+impl Related<super::comment_votes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentVotes.def()
+    }
+}
+
 impl Related<super::users::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Users.def()
@@ -0,0 +1,52 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::sea_orm_active_enums::{FlagReason, FlagStatus, ModerationAction, PostType};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "flags")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub post_type: PostType,
+    pub post_id: i32,
+    pub user_id: i32,
+    pub reason: FlagReason,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub text: Option<String>,
+    pub status: FlagStatus,
+    pub resolved_by: Option<i32>,
+    pub resolved_at: Option<DateTimeUtc>,
+    pub resolution_action: Option<ModerationAction>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub resolution_note: Option<String>,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_update = "Restrict",
+        on_delete = "Cascade"
+    )]
+    Users,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::ResolvedBy",
+        to = "super::users::Column::Id",
+        on_update = "Restrict",
+        on_delete = "SetNull"
+    )]
+    Resolver,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
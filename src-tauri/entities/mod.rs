@@ -2,9 +2,29 @@ pub mod prelude;
 
 pub mod answers;
 pub mod bookmarks;
+pub mod comment_mentions;
+pub mod comment_votes;
 pub mod comments;
+pub mod email_verifications;
+pub mod flags;
+pub mod notification_preferences;
+pub mod notifications;
+pub mod personal_access_tokens;
+pub mod post_links;
+pub mod question_follows;
 pub mod question_tags;
 pub mod questions;
+pub mod recovery_codes;
+pub mod review_decisions;
+pub mod review_tasks;
+pub mod search_documents;
+pub mod search_terms;
+pub mod sea_orm_active_enums;
+pub mod tag_audit_logs;
+pub mod tag_preferences;
+pub mod tag_revisions;
+pub mod tag_synonym_votes;
+pub mod tag_synonyms;
 pub mod tags;
 pub mod users;
 pub mod votes;
@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::sea_orm_active_enums::PostType;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "post_links")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub post_type: PostType,
+    pub post_id: i32,
+    pub question_id: i32,
+    pub linked_question_id: i32,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::questions::Entity",
+        from = "Column::QuestionId",
+        to = "super::questions::Column::Id",
+        on_update = "Restrict",
+        on_delete = "Cascade"
+    )]
+    Questions,
+    #[sea_orm(
+        belongs_to = "super::questions::Entity",
+        from = "Column::LinkedQuestionId",
+        to = "super::questions::Column::Id",
+        on_update = "Restrict",
+        on_delete = "Cascade"
+    )]
+    LinkedQuestions,
+}
+
+impl Related<super::questions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Questions.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,8 +1,27 @@
 pub use super::answers::Entity as Answers;
 pub use super::bookmarks::Entity as Bookmarks;
+pub use super::comment_mentions::Entity as CommentMentions;
+pub use super::comment_votes::Entity as CommentVotes;
 pub use super::comments::Entity as Comments;
+pub use super::email_verifications::Entity as EmailVerifications;
+pub use super::flags::Entity as Flags;
+pub use super::notification_preferences::Entity as NotificationPreferences;
+pub use super::notifications::Entity as Notifications;
+pub use super::personal_access_tokens::Entity as PersonalAccessTokens;
+pub use super::post_links::Entity as PostLinks;
+pub use super::question_follows::Entity as QuestionFollows;
 pub use super::question_tags::Entity as QuestionTags;
 pub use super::questions::Entity as Questions;
+pub use super::recovery_codes::Entity as RecoveryCodes;
+pub use super::review_decisions::Entity as ReviewDecisions;
+pub use super::review_tasks::Entity as ReviewTasks;
+pub use super::search_documents::Entity as SearchDocuments;
+pub use super::search_terms::Entity as SearchTerms;
+pub use super::tag_audit_logs::Entity as TagAuditLogs;
+pub use super::tag_preferences::Entity as TagPreferences;
+pub use super::tag_revisions::Entity as TagRevisions;
+pub use super::tag_synonym_votes::Entity as TagSynonymVotes;
+pub use super::tag_synonyms::Entity as TagSynonyms;
 pub use super::tags::Entity as Tags;
 pub use super::users::Entity as Users;
 pub use super::votes::Entity as Votes;
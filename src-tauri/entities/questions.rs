@@ -15,14 +15,24 @@ pub struct Model {
     pub view_count: i32,
     pub is_closed: i8,
     pub is_answered: i8,
+    pub locked_at: Option<DateTimeUtc>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub rendered_html: Option<String>,
+    pub render_version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::answers::Entity")]
     Answers,
+    #[sea_orm(has_many = "super::post_links::Entity")]
+    PostLinks,
+    #[sea_orm(has_many = "super::question_follows::Entity")]
+    QuestionFollows,
     #[sea_orm(has_many = "super::question_tags::Entity")]
     QuestionTags,
+    #[sea_orm(has_one = "super::search_documents::Entity")]
+    SearchDocuments,
     #[sea_orm(
         belongs_to = "super::users::Entity",
         from = "Column::UserId",
@@ -39,12 +49,30 @@ impl Related<super::answers::Entity> for Entity {
     }
 }
 
+impl Related<super::post_links::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PostLinks.def()
+    }
+}
+
+impl Related<super::question_follows::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QuestionFollows.def()
+    }
+}
+
 impl Related<super::question_tags::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::QuestionTags.def()
     }
 }
 
+impl Related<super::search_documents::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SearchDocuments.def()
+    }
+}
+
 impl Related<super::users::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Users.def()
@@ -0,0 +1,32 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::sea_orm_active_enums::{PostType, ReviewAction, ReviewQueue, ReviewStatus};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "review_tasks")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub queue: ReviewQueue,
+    pub post_type: PostType,
+    pub post_id: i32,
+    pub status: ReviewStatus,
+    pub outcome: Option<ReviewAction>,
+    pub created_at: DateTimeUtc,
+    pub completed_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::review_decisions::Entity")]
+    ReviewDecisions,
+}
+
+impl Related<super::review_decisions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReviewDecisions.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
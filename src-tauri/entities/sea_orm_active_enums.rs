@@ -0,0 +1,207 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "target_type")]
+#[serde(rename_all = "lowercase")]
+pub enum TargetType {
+    #[sea_orm(string_value = "question")]
+    Question,
+    #[sea_orm(string_value = "answer")]
+    Answer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "vote_type")]
+#[serde(rename_all = "lowercase")]
+pub enum VoteType {
+    #[sea_orm(string_value = "up")]
+    Up,
+    #[sea_orm(string_value = "down")]
+    Down,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "post_type")]
+#[serde(rename_all = "lowercase")]
+pub enum PostType {
+    #[sea_orm(string_value = "question")]
+    Question,
+    #[sea_orm(string_value = "answer")]
+    Answer,
+    #[sea_orm(string_value = "comment")]
+    Comment,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "flag_reason")]
+#[serde(rename_all = "snake_case")]
+pub enum FlagReason {
+    #[sea_orm(string_value = "spam")]
+    Spam,
+    #[sea_orm(string_value = "rude")]
+    Rude,
+    #[sea_orm(string_value = "no_longer_needed")]
+    NoLongerNeeded,
+    #[sea_orm(string_value = "off_topic")]
+    OffTopic,
+    #[sea_orm(string_value = "needs_improvement")]
+    NeedsImprovement,
+    #[sea_orm(string_value = "duplicate")]
+    Duplicate,
+    #[sea_orm(string_value = "other")]
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "flag_status")]
+#[serde(rename_all = "lowercase")]
+pub enum FlagStatus {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "helpful")]
+    Helpful,
+    #[sea_orm(string_value = "declined")]
+    Declined,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "moderation_action")]
+#[serde(rename_all = "lowercase")]
+pub enum ModerationAction {
+    #[sea_orm(string_value = "delete")]
+    Delete,
+    #[sea_orm(string_value = "lock")]
+    Lock,
+    #[sea_orm(string_value = "edit")]
+    Edit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "review_queue")]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewQueue {
+    #[sea_orm(string_value = "first_post")]
+    FirstPost,
+    #[sea_orm(string_value = "late_answer")]
+    LateAnswer,
+    #[sea_orm(string_value = "low_quality")]
+    LowQuality,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "review_status")]
+#[serde(rename_all = "lowercase")]
+pub enum ReviewStatus {
+    #[sea_orm(string_value = "open")]
+    Open,
+    #[sea_orm(string_value = "completed")]
+    Completed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "review_action")]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewAction {
+    #[sea_orm(string_value = "looks_ok")]
+    LooksOk,
+    #[sea_orm(string_value = "edit")]
+    Edit,
+    #[sea_orm(string_value = "recommend_deletion")]
+    RecommendDeletion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "notification_type")]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationType {
+    #[sea_orm(string_value = "answer")]
+    Answer,
+    #[sea_orm(string_value = "comment")]
+    Comment,
+    #[sea_orm(string_value = "reply")]
+    Reply,
+    #[sea_orm(string_value = "mention")]
+    Mention,
+    #[sea_orm(string_value = "accepted")]
+    Accepted,
+    #[sea_orm(string_value = "reputation")]
+    Reputation,
+    #[sea_orm(string_value = "edit")]
+    Edit,
+    #[sea_orm(string_value = "solved")]
+    Solved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "tag_preference")]
+#[serde(rename_all = "lowercase")]
+pub enum TagPreference {
+    #[sea_orm(string_value = "watched")]
+    Watched,
+    #[sea_orm(string_value = "ignored")]
+    Ignored,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "tag_audit_action")]
+#[serde(rename_all = "lowercase")]
+pub enum TagAuditAction {
+    #[sea_orm(string_value = "update")]
+    Update,
+    #[sea_orm(string_value = "rename")]
+    Rename,
+    #[sea_orm(string_value = "merge")]
+    Merge,
+    #[sea_orm(string_value = "delete")]
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "synonym_status")]
+#[serde(rename_all = "lowercase")]
+pub enum SynonymStatus {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "approved")]
+    Approved,
+    #[sea_orm(string_value = "rejected")]
+    Rejected,
+}
+
+impl ReviewQueue {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewQueue::FirstPost => "first posts",
+            ReviewQueue::LateAnswer => "late answers",
+            ReviewQueue::LowQuality => "low quality posts",
+        }
+    }
+}
+
+impl TargetType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TargetType::Question => "question",
+            TargetType::Answer => "answer",
+        }
+    }
+}
+
+impl From<TargetType> for PostType {
+    fn from(target_type: TargetType) -> Self {
+        match target_type {
+            TargetType::Question => PostType::Question,
+            TargetType::Answer => PostType::Answer,
+        }
+    }
+}
+
+impl VoteType {
+    pub fn score(&self) -> i32 {
+        match self {
+            VoteType::Up => 1,
+            VoteType::Down => -1,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "search_documents")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub question_id: i32,
+    pub title_length: i32,
+    pub body_length: i32,
+    pub indexed_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::questions::Entity",
+        from = "Column::QuestionId",
+        to = "super::questions::Column::Id",
+        on_update = "Restrict",
+        on_delete = "Cascade"
+    )]
+    Questions,
+    #[sea_orm(has_many = "super::search_terms::Entity")]
+    SearchTerms,
+}
+
+impl Related<super::questions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Questions.def()
+    }
+}
+
+impl Related<super::search_terms::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SearchTerms.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,33 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "search_terms")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub question_id: i32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub term: String,
+    pub title_frequency: i32,
+    pub body_frequency: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::search_documents::Entity",
+        from = "Column::QuestionId",
+        to = "super::search_documents::Column::QuestionId",
+        on_update = "Restrict",
+        on_delete = "Cascade"
+    )]
+    SearchDocuments,
+}
+
+impl Related<super::search_documents::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SearchDocuments.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
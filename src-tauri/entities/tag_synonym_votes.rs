@@ -0,0 +1,49 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::sea_orm_active_enums::VoteType;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "tag_synonym_votes")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub synonym_id: i32,
+    pub user_id: i32,
+    pub vote_type: VoteType,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::tag_synonyms::Entity",
+        from = "Column::SynonymId",
+        to = "super::tag_synonyms::Column::Id",
+        on_update = "Restrict",
+        on_delete = "Cascade"
+    )]
+    TagSynonyms,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_update = "Restrict",
+        on_delete = "Cascade"
+    )]
+    Users,
+}
+
+impl Related<super::tag_synonyms::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagSynonyms.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,70 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::sea_orm_active_enums::SynonymStatus;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "tag_synonyms")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub source_name: String,
+    pub target_tag_id: i32,
+    pub suggested_by: Option<i32>,
+    pub status: SynonymStatus,
+    pub score: i32,
+    pub reviewed_by: Option<i32>,
+    pub reviewed_at: Option<DateTimeUtc>,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::tags::Entity",
+        from = "Column::TargetTagId",
+        to = "super::tags::Column::Id",
+        on_update = "Restrict",
+        on_delete = "Cascade"
+    )]
+    Tags,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::SuggestedBy",
+        to = "super::users::Column::Id",
+        on_update = "Restrict",
+        on_delete = "SetNull"
+    )]
+    Users,
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::ReviewedBy",
+        to = "super::users::Column::Id",
+        on_update = "Restrict",
+        on_delete = "SetNull"
+    )]
+    Reviewer,
+    #[sea_orm(has_many = "super::tag_synonym_votes::Entity")]
+    TagSynonymVotes,
+}
+
+impl Related<super::tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tags.def()
+    }
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Users.def()
+    }
+}
+
+impl Related<super::tag_synonym_votes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagSynonymVotes.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
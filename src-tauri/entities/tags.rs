@@ -9,13 +9,21 @@ pub struct Model {
     #[sea_orm(unique)]
     pub name: String,
     #[sea_orm(column_type = "Text", nullable)]
-    pub description: Option<String>,
+    pub excerpt: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub wiki: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::question_tags::Entity")]
     QuestionTags,
+    #[sea_orm(has_many = "super::tag_preferences::Entity")]
+    TagPreferences,
+    #[sea_orm(has_many = "super::tag_revisions::Entity")]
+    TagRevisions,
+    #[sea_orm(has_many = "super::tag_synonyms::Entity")]
+    TagSynonyms,
 }
 
 impl Related<super::question_tags::Entity> for Entity {
@@ -24,6 +32,24 @@ impl Related<super::question_tags::Entity> for Entity {
     }
 }
 
+impl Related<super::tag_preferences::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagPreferences.def()
+    }
+}
+
+impl Related<super::tag_revisions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagRevisions.def()
+    }
+}
+
+impl Related<super::tag_synonyms::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagSynonyms.def()
+    }
+}
+
 impl Related<super::questions::Entity> for Entity {
     fn to() -> RelationDef {
         super::question_tags::Relation::Questions.def()
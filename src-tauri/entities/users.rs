@@ -19,6 +19,14 @@ pub struct Model {
     pub last_login: Option<DateTimeUtc>,
     pub avatar_url: Option<String>,
     pub is_active: i8,
+    pub verified_at: Option<DateTimeUtc>,
+    pub totp_secret: Option<String>,
+    pub totp_enabled_at: Option<DateTimeUtc>,
+    pub totp_last_step: Option<i64>,
+    pub two_factor_failed_attempts: i32,
+    pub two_factor_locked_until: Option<DateTimeUtc>,
+    pub is_moderator: bool,
+    pub hide_ignored_tags: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -27,10 +35,40 @@ pub enum Relation {
     Answers,
     #[sea_orm(has_many = "super::bookmarks::Entity")]
     Bookmarks,
+    #[sea_orm(has_many = "super::comment_mentions::Entity")]
+    CommentMentions,
+    #[sea_orm(has_many = "super::comment_votes::Entity")]
+    CommentVotes,
     #[sea_orm(has_many = "super::comments::Entity")]
     Comments,
+    #[sea_orm(has_many = "super::email_verifications::Entity")]
+    EmailVerifications,
+    #[sea_orm(has_many = "super::flags::Entity")]
+    Flags,
+    #[sea_orm(has_many = "super::review_decisions::Entity")]
+    ReviewDecisions,
+    #[sea_orm(has_many = "super::notifications::Entity")]
+    Notifications,
+    #[sea_orm(has_many = "super::notification_preferences::Entity")]
+    NotificationPreferences,
+    #[sea_orm(has_many = "super::question_follows::Entity")]
+    QuestionFollows,
+    #[sea_orm(has_many = "super::tag_audit_logs::Entity")]
+    TagAuditLogs,
+    #[sea_orm(has_many = "super::tag_preferences::Entity")]
+    TagPreferences,
+    #[sea_orm(has_many = "super::tag_revisions::Entity")]
+    TagRevisions,
+    #[sea_orm(has_many = "super::tag_synonym_votes::Entity")]
+    TagSynonymVotes,
+    #[sea_orm(has_many = "super::tag_synonyms::Entity")]
+    TagSynonyms,
+    #[sea_orm(has_many = "super::personal_access_tokens::Entity")]
+    PersonalAccessTokens,
     #[sea_orm(has_many = "super::questions::Entity")]
     Questions,
+    #[sea_orm(has_many = "super::recovery_codes::Entity")]
+    RecoveryCodes,
     #[sea_orm(has_many = "super::votes::Entity")]
     Votes,
 }
@@ -47,18 +85,108 @@ impl Related<super::bookmarks::Entity> for Entity {
     }
 }
 
+impl Related<super::comment_mentions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentMentions.def()
+    }
+}
+
+impl Related<super::comment_votes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentVotes.def()
+    }
+}
+
 impl Related<super::comments::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Comments.def()
     }
 }
 
+impl Related<super::email_verifications::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EmailVerifications.def()
+    }
+}
+
+impl Related<super::flags::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Flags.def()
+    }
+}
+
+impl Related<super::review_decisions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReviewDecisions.def()
+    }
+}
+
+impl Related<super::notifications::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Notifications.def()
+    }
+}
+
+impl Related<super::notification_preferences::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NotificationPreferences.def()
+    }
+}
+
+impl Related<super::question_follows::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QuestionFollows.def()
+    }
+}
+
+impl Related<super::tag_audit_logs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagAuditLogs.def()
+    }
+}
+
+impl Related<super::tag_preferences::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagPreferences.def()
+    }
+}
+
+impl Related<super::tag_revisions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagRevisions.def()
+    }
+}
+
+impl Related<super::tag_synonym_votes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagSynonymVotes.def()
+    }
+}
+
+impl Related<super::tag_synonyms::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagSynonyms.def()
+    }
+}
+
+impl Related<super::personal_access_tokens::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PersonalAccessTokens.def()
+    }
+}
+
 impl Related<super::questions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Questions.def()
     }
 }
 
+impl Related<super::recovery_codes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecoveryCodes.def()
+    }
+}
+
 impl Related<super::votes::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Votes.def()
@@ -1,15 +1,17 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::sea_orm_active_enums::{TargetType, VoteType};
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "votes")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub user_id: i32,
-    pub vote_type: String,
+    pub vote_type: VoteType,
     pub target_id: i32,
-    pub target_type: String,
+    pub target_type: TargetType,
     pub created_at: DateTimeUtc,
 }
 
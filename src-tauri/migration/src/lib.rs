@@ -8,6 +8,26 @@ mod m20250313_030800_create_tags;
 mod m20250313_030810_create_question_tags;
 mod m20250313_030821_create_votes;
 mod m20250313_030840_create_bookmarks;
+mod m20250320_090000_add_email_verification;
+mod m20250322_090000_add_two_factor;
+mod m20250325_090000_create_personal_access_tokens;
+mod m20250328_090000_use_enum_types;
+mod m20250401_090000_add_comment_threads;
+mod m20250403_090000_add_comment_votes_and_flags;
+mod m20250405_090000_create_flags;
+mod m20250407_090000_create_review_queues;
+mod m20250409_090000_create_notifications;
+mod m20250411_090000_create_follows_and_tag_preferences;
+mod m20250413_090000_create_tag_audit_log;
+mod m20250415_090000_create_tag_synonyms;
+mod m20250417_090000_normalize_tag_names;
+mod m20250419_090000_add_tag_wiki;
+mod m20250421_090000_add_tag_autocomplete_indexes;
+mod m20250423_090000_create_post_links;
+mod m20250425_090000_create_search_index;
+mod m20250427_090000_add_rendered_html;
+mod m20250501_090000_add_render_version;
+mod m20250503_090000_add_two_factor_throttling;
 
 pub struct Migrator;
 
@@ -23,6 +43,26 @@ impl MigratorTrait for Migrator {
             Box::new(m20250313_030810_create_question_tags::Migration),
             Box::new(m20250313_030821_create_votes::Migration),
             Box::new(m20250313_030840_create_bookmarks::Migration),
+            Box::new(m20250320_090000_add_email_verification::Migration),
+            Box::new(m20250322_090000_add_two_factor::Migration),
+            Box::new(m20250325_090000_create_personal_access_tokens::Migration),
+            Box::new(m20250328_090000_use_enum_types::Migration),
+            Box::new(m20250401_090000_add_comment_threads::Migration),
+            Box::new(m20250403_090000_add_comment_votes_and_flags::Migration),
+            Box::new(m20250405_090000_create_flags::Migration),
+            Box::new(m20250407_090000_create_review_queues::Migration),
+            Box::new(m20250409_090000_create_notifications::Migration),
+            Box::new(m20250411_090000_create_follows_and_tag_preferences::Migration),
+            Box::new(m20250413_090000_create_tag_audit_log::Migration),
+            Box::new(m20250415_090000_create_tag_synonyms::Migration),
+            Box::new(m20250417_090000_normalize_tag_names::Migration),
+            Box::new(m20250419_090000_add_tag_wiki::Migration),
+            Box::new(m20250421_090000_add_tag_autocomplete_indexes::Migration),
+            Box::new(m20250423_090000_create_post_links::Migration),
+            Box::new(m20250425_090000_create_search_index::Migration),
+            Box::new(m20250427_090000_add_rendered_html::Migration),
+            Box::new(m20250501_090000_add_render_version::Migration),
+            Box::new(m20250503_090000_add_two_factor_throttling::Migration),
         ]
     }
 }
@@ -0,0 +1,115 @@
+use crate::m20250313_030734_create_users::Users;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(UserVerification::VerifiedAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Users::Table)
+                    .value(UserVerification::VerifiedAt, Expr::current_timestamp())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE users SET email = LOWER(TRIM(email)) \
+                 WHERE email <> LOWER(TRIM(email)) AND NOT EXISTS ( \
+                     SELECT 1 FROM users other \
+                     WHERE LOWER(TRIM(other.email)) = LOWER(TRIM(users.email)) AND other.id <> users.id \
+                     AND (other.email = LOWER(TRIM(other.email)) OR other.id < users.id) \
+                 )",
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(EmailVerifications::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EmailVerifications::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(EmailVerifications::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(EmailVerifications::TokenHash)
+                            .string()
+                            .not_null()
+                            .unique_key()
+                    )
+                    .col(
+                        ColumnDef::new(EmailVerifications::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(EmailVerifications::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_email_verifications_users")
+                            .from(EmailVerifications::Table, EmailVerifications::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EmailVerifications::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(UserVerification::VerifiedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserVerification {
+    VerifiedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum EmailVerifications {
+    Table,
+    Id,
+    UserId,
+    TokenHash,
+    ExpiresAt,
+    CreatedAt,
+}
@@ -0,0 +1,85 @@
+use crate::m20250313_030734_create_users::Users;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PersonalAccessTokens::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PersonalAccessTokens::UserId).integer().not_null())
+                    .col(ColumnDef::new(PersonalAccessTokens::Name).string().not_null())
+                    .col(ColumnDef::new(PersonalAccessTokens::TokenPrefix).string().not_null())
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::TokenHash)
+                            .string()
+                            .not_null()
+                            .unique_key()
+                    )
+                    .col(ColumnDef::new(PersonalAccessTokens::Scopes).string().not_null())
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::LastUsedAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::RevokedAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_personal_access_tokens_users")
+                            .from(PersonalAccessTokens::Table, PersonalAccessTokens::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PersonalAccessTokens::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum PersonalAccessTokens {
+    Table,
+    Id,
+    UserId,
+    Name,
+    TokenPrefix,
+    TokenHash,
+    Scopes,
+    ExpiresAt,
+    LastUsedAt,
+    RevokedAt,
+    CreatedAt,
+}
@@ -0,0 +1,145 @@
+use crate::m20250313_030821_create_votes::{TargetType, VoteType};
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TARGET_TYPE_COLUMNS: [(&str, &str); 3] = [
+    ("votes", "target_type"),
+    ("comments", "target_type"),
+    ("bookmarks", "target_type"),
+];
+const TARGET_TYPE_VALUES: &str = "'question', 'answer'";
+const VOTE_TYPE_VALUES: &str = "'up', 'down'";
+
+fn checked_columns() -> impl Iterator<Item = (&'static str, &'static str, &'static str)> {
+    TARGET_TYPE_COLUMNS
+        .into_iter()
+        .map(|(table, column)| (table, column, TARGET_TYPE_VALUES))
+        .chain([("votes", "vote_type", VOTE_TYPE_VALUES)])
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        for (table, column) in TARGET_TYPE_COLUMNS.into_iter().chain([("votes", "vote_type")]) {
+            db.execute_unprepared(&format!(
+                "UPDATE {table} SET {column} = LOWER(TRIM({column}))"
+            ))
+            .await?;
+        }
+
+        db.execute_unprepared("UPDATE votes SET vote_type = 'up' WHERE vote_type IN ('upvote', '+1', '1')")
+            .await?;
+        db.execute_unprepared("UPDATE votes SET vote_type = 'down' WHERE vote_type IN ('downvote', '-1')")
+            .await?;
+
+        // Rows that still do not name a known value cannot be scored or displayed, so they are dropped.
+        db.execute_unprepared(&format!("DELETE FROM votes WHERE vote_type NOT IN ({VOTE_TYPE_VALUES})"))
+            .await?;
+        for (table, column) in TARGET_TYPE_COLUMNS {
+            db.execute_unprepared(&format!(
+                "DELETE FROM {table} WHERE {column} NOT IN ({TARGET_TYPE_VALUES})"
+            ))
+            .await?;
+        }
+
+        match manager.get_database_backend() {
+            DatabaseBackend::Postgres => {}
+            DatabaseBackend::MySql => {
+                for (table, column, values) in checked_columns() {
+                    db.execute_unprepared(&format!(
+                        "ALTER TABLE {table} ADD CONSTRAINT chk_{table}_{column} CHECK ({column} IN ({values}))"
+                    ))
+                    .await?;
+                }
+                return Ok(());
+            }
+            DatabaseBackend::Sqlite => {
+                for (table, column, values) in checked_columns() {
+                    for (suffix, event) in [("insert", "INSERT".to_string()), ("update", format!("UPDATE OF {column}"))] {
+                        db.execute_unprepared(&format!(
+                            "CREATE TRIGGER IF NOT EXISTS chk_{table}_{column}_{suffix} BEFORE {event} ON {table} \
+                             WHEN NEW.{column} NOT IN ({values}) \
+                             BEGIN SELECT RAISE(ABORT, 'invalid {column} on {table}'); END"
+                        ))
+                        .await?;
+                    }
+                }
+                return Ok(());
+            }
+        }
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(TargetType::Table)
+                    .values([TargetType::Question, TargetType::Answer])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(VoteType::Table)
+                    .values([VoteType::Up, VoteType::Down])
+                    .to_owned(),
+            )
+            .await?;
+
+        for (table, column) in TARGET_TYPE_COLUMNS {
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} ALTER COLUMN {column} TYPE target_type USING {column}::target_type"
+            ))
+            .await?;
+        }
+        db.execute_unprepared("ALTER TABLE votes ALTER COLUMN vote_type TYPE vote_type USING vote_type::vote_type")
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        match manager.get_database_backend() {
+            DatabaseBackend::Postgres => {}
+            DatabaseBackend::MySql => {
+                for (table, column, _) in checked_columns() {
+                    db.execute_unprepared(&format!("ALTER TABLE {table} DROP CHECK chk_{table}_{column}"))
+                        .await?;
+                }
+                return Ok(());
+            }
+            DatabaseBackend::Sqlite => {
+                for (table, column, _) in checked_columns() {
+                    for suffix in ["insert", "update"] {
+                        db.execute_unprepared(&format!("DROP TRIGGER IF EXISTS chk_{table}_{column}_{suffix}"))
+                            .await?;
+                    }
+                }
+                return Ok(());
+            }
+        }
+
+        for (table, column) in TARGET_TYPE_COLUMNS.into_iter().chain([("votes", "vote_type")]) {
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} ALTER COLUMN {column} TYPE varchar USING {column}::text"
+            ))
+            .await?;
+        }
+
+        manager
+            .drop_type(Type::drop().name(VoteType::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(TargetType::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,131 @@
+use crate::m20250313_030734_create_users::Users;
+use crate::m20250313_030756_create_comments::Comments;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .add_column(
+                        ColumnDef::new(CommentThreads::ParentCommentId)
+                            .integer()
+                            .null()
+                    )
+                    .add_column(
+                        ColumnDef::new(CommentThreads::Depth)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_comments_parent_comment")
+                            .from_tbl(Comments::Table)
+                            .from_col(CommentThreads::ParentCommentId)
+                            .to_tbl(Comments::Table)
+                            .to_col(Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comments_target")
+                    .table(Comments::Table)
+                    .col(Comments::TargetType)
+                    .col(Comments::TargetId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentMentions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommentMentions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CommentMentions::CommentId).integer().not_null())
+                    .col(ColumnDef::new(CommentMentions::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(CommentMentions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_mentions_comments")
+                            .from(CommentMentions::Table, CommentMentions::CommentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_mentions_users")
+                            .from(CommentMentions::Table, CommentMentions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_comment_mentions_comment_user")
+                            .col(CommentMentions::CommentId)
+                            .col(CommentMentions::UserId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentMentions::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("idx_comments_target").table(Comments::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .drop_foreign_key(Alias::new("fk_comments_parent_comment"))
+                    .drop_column(CommentThreads::ParentCommentId)
+                    .drop_column(CommentThreads::Depth)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommentThreads {
+    ParentCommentId,
+    Depth,
+}
+
+#[derive(DeriveIden)]
+pub enum CommentMentions {
+    Table,
+    Id,
+    CommentId,
+    UserId,
+    CreatedAt,
+}
@@ -0,0 +1,203 @@
+use crate::m20250313_030734_create_users::Users;
+use crate::m20250313_030756_create_comments::Comments;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .add_column(
+                        ColumnDef::new(CommentModeration::HiddenAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentVotes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommentVotes::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CommentVotes::CommentId).integer().not_null())
+                    .col(ColumnDef::new(CommentVotes::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(CommentVotes::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_votes_comments")
+                            .from(CommentVotes::Table, CommentVotes::CommentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_votes_users")
+                            .from(CommentVotes::Table, CommentVotes::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_comment_votes_comment_user")
+                            .col(CommentVotes::CommentId)
+                            .col(CommentVotes::UserId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(CommentFlagReason::Table)
+                        .values([
+                            CommentFlagReason::Rude,
+                            CommentFlagReason::NoLongerNeeded,
+                            CommentFlagReason::Spam,
+                        ])
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentFlags::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommentFlags::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CommentFlags::CommentId).integer().not_null())
+                    .col(ColumnDef::new(CommentFlags::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(CommentFlags::Reason)
+                            .enumeration(
+                                CommentFlagReason::Table,
+                                [
+                                    CommentFlagReason::Rude,
+                                    CommentFlagReason::NoLongerNeeded,
+                                    CommentFlagReason::Spam,
+                                ],
+                            )
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(CommentFlags::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_flags_comments")
+                            .from(CommentFlags::Table, CommentFlags::CommentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_flags_users")
+                            .from(CommentFlags::Table, CommentFlags::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_comment_flags_comment_user")
+                            .col(CommentFlags::CommentId)
+                            .col(CommentFlags::UserId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentFlags::Table).to_owned())
+            .await?;
+
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .drop_type(Type::drop().name(CommentFlagReason::Table).to_owned())
+                .await?;
+        }
+
+        manager
+            .drop_table(Table::drop().table(CommentVotes::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comments::Table)
+                    .drop_column(CommentModeration::HiddenAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommentModeration {
+    HiddenAt,
+}
+
+#[derive(DeriveIden)]
+pub enum CommentVotes {
+    Table,
+    Id,
+    CommentId,
+    UserId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum CommentFlags {
+    Table,
+    Id,
+    CommentId,
+    UserId,
+    Reason,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum CommentFlagReason {
+    Table,
+    #[sea_orm(iden = "rude")]
+    Rude,
+    #[sea_orm(iden = "no_longer_needed")]
+    NoLongerNeeded,
+    #[sea_orm(iden = "spam")]
+    Spam,
+}
@@ -0,0 +1,405 @@
+use crate::m20250313_030734_create_users::Users;
+use crate::m20250313_030738_create_questions::Questions;
+use crate::m20250313_030743_create_answers::Answers;
+use crate::m20250403_090000_add_comment_votes_and_flags::{CommentFlagReason, CommentFlags};
+use crate::m20250313_030756_create_comments::Comments;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let is_postgres = manager.get_database_backend() == DatabaseBackend::Postgres;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Moderation::IsModerator)
+                            .boolean()
+                            .not_null()
+                            .default(false)
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        for table in [Questions::Table.into_iden(), Answers::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(
+                            ColumnDef::new(Moderation::LockedAt)
+                                .timestamp_with_time_zone()
+                                .null()
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        if is_postgres {
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(PostType::Table)
+                        .values([PostType::Question, PostType::Answer, PostType::Comment])
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(FlagReason::Table)
+                        .values(FlagReason::values())
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(FlagStatus::Table)
+                        .values([FlagStatus::Pending, FlagStatus::Helpful, FlagStatus::Declined])
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(ModerationAction::Table)
+                        .values([ModerationAction::Delete, ModerationAction::Lock, ModerationAction::Edit])
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Flags::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Flags::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Flags::PostType)
+                            .enumeration(PostType::Table, [PostType::Question, PostType::Answer, PostType::Comment])
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(Flags::PostId).integer().not_null())
+                    .col(ColumnDef::new(Flags::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(Flags::Reason)
+                            .enumeration(FlagReason::Table, FlagReason::values())
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(Flags::Text).text().null())
+                    .col(
+                        ColumnDef::new(Flags::Status)
+                            .enumeration(FlagStatus::Table, [FlagStatus::Pending, FlagStatus::Helpful, FlagStatus::Declined])
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(Flags::ResolvedBy).integer().null())
+                    .col(
+                        ColumnDef::new(Flags::ResolvedAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .col(
+                        ColumnDef::new(Flags::ResolutionAction)
+                            .enumeration(ModerationAction::Table, [ModerationAction::Delete, ModerationAction::Lock, ModerationAction::Edit])
+                            .null()
+                    )
+                    .col(ColumnDef::new(Flags::ResolutionNote).text().null())
+                    .col(
+                        ColumnDef::new(Flags::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_flags_users")
+                            .from(Flags::Table, Flags::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_flags_resolved_by")
+                            .from(Flags::Table, Flags::ResolvedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_flags_post_user")
+                            .col(Flags::PostType)
+                            .col(Flags::PostId)
+                            .col(Flags::UserId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_flags_status")
+                    .table(Flags::Table)
+                    .col(Flags::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        let copy_comment_flags = if is_postgres {
+            "INSERT INTO flags (post_type, post_id, user_id, reason, status, created_at) \
+             SELECT 'comment'::post_type, comment_id, user_id, reason::text::flag_reason, 'pending'::flag_status, created_at FROM comment_flags"
+        } else {
+            "INSERT INTO flags (post_type, post_id, user_id, reason, status, created_at) \
+             SELECT 'comment', comment_id, user_id, reason, 'pending', created_at FROM comment_flags"
+        };
+        manager.get_connection().execute_unprepared(copy_comment_flags).await?;
+
+        manager
+            .drop_table(Table::drop().table(CommentFlags::Table).to_owned())
+            .await?;
+
+        if is_postgres {
+            manager
+                .drop_type(Type::drop().name(CommentFlagReason::Table).to_owned())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let is_postgres = manager.get_database_backend() == DatabaseBackend::Postgres;
+
+        if is_postgres {
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(CommentFlagReason::Table)
+                        .values([
+                            CommentFlagReason::Rude,
+                            CommentFlagReason::NoLongerNeeded,
+                            CommentFlagReason::Spam,
+                        ])
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentFlags::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommentFlags::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CommentFlags::CommentId).integer().not_null())
+                    .col(ColumnDef::new(CommentFlags::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(CommentFlags::Reason)
+                            .enumeration(
+                                CommentFlagReason::Table,
+                                [
+                                    CommentFlagReason::Rude,
+                                    CommentFlagReason::NoLongerNeeded,
+                                    CommentFlagReason::Spam,
+                                ],
+                            )
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(CommentFlags::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_flags_comments")
+                            .from(CommentFlags::Table, CommentFlags::CommentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_flags_users")
+                            .from(CommentFlags::Table, CommentFlags::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_comment_flags_comment_user")
+                            .col(CommentFlags::CommentId)
+                            .col(CommentFlags::UserId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let copy_comment_flags = if is_postgres {
+            "INSERT INTO comment_flags (comment_id, user_id, reason, created_at) \
+             SELECT post_id, user_id, reason::text::comment_flag_reason, created_at FROM flags \
+             WHERE post_type = 'comment' AND status = 'pending' AND reason::text IN ('rude', 'no_longer_needed', 'spam') \
+             AND post_id IN (SELECT id FROM comments)"
+        } else {
+            "INSERT INTO comment_flags (comment_id, user_id, reason, created_at) \
+             SELECT post_id, user_id, reason, created_at FROM flags \
+             WHERE post_type = 'comment' AND status = 'pending' AND reason IN ('rude', 'no_longer_needed', 'spam') \
+             AND post_id IN (SELECT id FROM comments)"
+        };
+        manager.get_connection().execute_unprepared(copy_comment_flags).await?;
+
+        manager
+            .drop_table(Table::drop().table(Flags::Table).to_owned())
+            .await?;
+
+        if is_postgres {
+            for name in [
+                ModerationAction::Table.into_iden(),
+                FlagStatus::Table.into_iden(),
+                FlagReason::Table.into_iden(),
+                PostType::Table.into_iden(),
+            ] {
+                manager
+                    .drop_type(Type::drop().name(name).to_owned())
+                    .await?;
+            }
+        }
+
+        for table in [Questions::Table.into_iden(), Answers::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(Moderation::LockedAt)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Moderation::IsModerator)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Moderation {
+    IsModerator,
+    LockedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum Flags {
+    Table,
+    Id,
+    PostType,
+    PostId,
+    UserId,
+    Reason,
+    Text,
+    Status,
+    ResolvedBy,
+    ResolvedAt,
+    ResolutionAction,
+    ResolutionNote,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum PostType {
+    Table,
+    #[sea_orm(iden = "question")]
+    Question,
+    #[sea_orm(iden = "answer")]
+    Answer,
+    #[sea_orm(iden = "comment")]
+    Comment,
+}
+
+#[derive(DeriveIden)]
+pub enum FlagReason {
+    Table,
+    #[sea_orm(iden = "spam")]
+    Spam,
+    #[sea_orm(iden = "rude")]
+    Rude,
+    #[sea_orm(iden = "no_longer_needed")]
+    NoLongerNeeded,
+    #[sea_orm(iden = "off_topic")]
+    OffTopic,
+    #[sea_orm(iden = "needs_improvement")]
+    NeedsImprovement,
+    #[sea_orm(iden = "duplicate")]
+    Duplicate,
+    #[sea_orm(iden = "other")]
+    Other,
+}
+
+impl FlagReason {
+    fn values() -> [FlagReason; 7] {
+        [
+            FlagReason::Spam,
+            FlagReason::Rude,
+            FlagReason::NoLongerNeeded,
+            FlagReason::OffTopic,
+            FlagReason::NeedsImprovement,
+            FlagReason::Duplicate,
+            FlagReason::Other,
+        ]
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum FlagStatus {
+    Table,
+    #[sea_orm(iden = "pending")]
+    Pending,
+    #[sea_orm(iden = "helpful")]
+    Helpful,
+    #[sea_orm(iden = "declined")]
+    Declined,
+}
+
+#[derive(DeriveIden)]
+pub enum ModerationAction {
+    Table,
+    #[sea_orm(iden = "delete")]
+    Delete,
+    #[sea_orm(iden = "lock")]
+    Lock,
+    #[sea_orm(iden = "edit")]
+    Edit,
+}
@@ -0,0 +1,239 @@
+use crate::m20250313_030734_create_users::Users;
+use crate::m20250405_090000_create_flags::PostType;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(ReviewQueue::Table)
+                        .values([ReviewQueue::FirstPost, ReviewQueue::LateAnswer, ReviewQueue::LowQuality])
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(ReviewStatus::Table)
+                        .values([ReviewStatus::Open, ReviewStatus::Completed])
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(ReviewAction::Table)
+                        .values([ReviewAction::LooksOk, ReviewAction::Edit, ReviewAction::RecommendDeletion])
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReviewTasks::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ReviewTasks::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ReviewTasks::Queue)
+                            .enumeration(ReviewQueue::Table, [ReviewQueue::FirstPost, ReviewQueue::LateAnswer, ReviewQueue::LowQuality])
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(ReviewTasks::PostType)
+                            .enumeration(PostType::Table, [PostType::Question, PostType::Answer, PostType::Comment])
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(ReviewTasks::PostId).integer().not_null())
+                    .col(
+                        ColumnDef::new(ReviewTasks::Status)
+                            .enumeration(ReviewStatus::Table, [ReviewStatus::Open, ReviewStatus::Completed])
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(ReviewTasks::Outcome)
+                            .enumeration(ReviewAction::Table, [ReviewAction::LooksOk, ReviewAction::Edit, ReviewAction::RecommendDeletion])
+                            .null()
+                    )
+                    .col(
+                        ColumnDef::new(ReviewTasks::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(ReviewTasks::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_review_tasks_queue_post")
+                            .col(ReviewTasks::Queue)
+                            .col(ReviewTasks::PostType)
+                            .col(ReviewTasks::PostId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_review_tasks_queue_status")
+                    .table(ReviewTasks::Table)
+                    .col(ReviewTasks::Queue)
+                    .col(ReviewTasks::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReviewDecisions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ReviewDecisions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ReviewDecisions::TaskId).integer().not_null())
+                    .col(ColumnDef::new(ReviewDecisions::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(ReviewDecisions::Action)
+                            .enumeration(ReviewAction::Table, [ReviewAction::LooksOk, ReviewAction::Edit, ReviewAction::RecommendDeletion])
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(ReviewDecisions::Note).text().null())
+                    .col(
+                        ColumnDef::new(ReviewDecisions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_review_decisions_review_tasks")
+                            .from(ReviewDecisions::Table, ReviewDecisions::TaskId)
+                            .to(ReviewTasks::Table, ReviewTasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_review_decisions_users")
+                            .from(ReviewDecisions::Table, ReviewDecisions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_review_decisions_task_user")
+                            .col(ReviewDecisions::TaskId)
+                            .col(ReviewDecisions::UserId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReviewDecisions::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ReviewTasks::Table).to_owned())
+            .await?;
+
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            for name in [
+                ReviewAction::Table.into_iden(),
+                ReviewStatus::Table.into_iden(),
+                ReviewQueue::Table.into_iden(),
+            ] {
+                manager
+                    .drop_type(Type::drop().name(name).to_owned())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ReviewTasks {
+    Table,
+    Id,
+    Queue,
+    PostType,
+    PostId,
+    Status,
+    Outcome,
+    CreatedAt,
+    CompletedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum ReviewDecisions {
+    Table,
+    Id,
+    TaskId,
+    UserId,
+    Action,
+    Note,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum ReviewQueue {
+    Table,
+    #[sea_orm(iden = "first_post")]
+    FirstPost,
+    #[sea_orm(iden = "late_answer")]
+    LateAnswer,
+    #[sea_orm(iden = "low_quality")]
+    LowQuality,
+}
+
+#[derive(DeriveIden)]
+pub enum ReviewStatus {
+    Table,
+    #[sea_orm(iden = "open")]
+    Open,
+    #[sea_orm(iden = "completed")]
+    Completed,
+}
+
+#[derive(DeriveIden)]
+pub enum ReviewAction {
+    Table,
+    #[sea_orm(iden = "looks_ok")]
+    LooksOk,
+    #[sea_orm(iden = "edit")]
+    Edit,
+    #[sea_orm(iden = "recommend_deletion")]
+    RecommendDeletion,
+}
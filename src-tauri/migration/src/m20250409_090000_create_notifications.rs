@@ -0,0 +1,216 @@
+use crate::m20250313_030734_create_users::Users;
+use crate::m20250405_090000_create_flags::PostType;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(NotificationType::Table)
+                        .values(NotificationType::values())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Notifications::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Notifications::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Notifications::UserId).integer().not_null())
+                    .col(ColumnDef::new(Notifications::ActorId).integer().null())
+                    .col(
+                        ColumnDef::new(Notifications::NotificationType)
+                            .enumeration(NotificationType::Table, NotificationType::values())
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::PostType)
+                            .enumeration(PostType::Table, [PostType::Question, PostType::Answer, PostType::Comment])
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(Notifications::PostId).integer().not_null())
+                    .col(ColumnDef::new(Notifications::QuestionId).integer().not_null())
+                    .col(
+                        ColumnDef::new(Notifications::ReputationChange)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::ReadAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notifications_users")
+                            .from(Notifications::Table, Notifications::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notifications_actors")
+                            .from(Notifications::Table, Notifications::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notifications_user_read")
+                    .table(Notifications::Table)
+                    .col(Notifications::UserId)
+                    .col(Notifications::ReadAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationPreferences::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NotificationPreferences::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(NotificationPreferences::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(NotificationPreferences::NotificationType)
+                            .enumeration(NotificationType::Table, NotificationType::values())
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(NotificationPreferences::Enabled).boolean().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_preferences_users")
+                            .from(NotificationPreferences::Table, NotificationPreferences::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_notification_preferences_user_type")
+                            .col(NotificationPreferences::UserId)
+                            .col(NotificationPreferences::NotificationType)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(NotificationPreferences::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Notifications::Table).to_owned())
+            .await?;
+
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .drop_type(Type::drop().name(NotificationType::Table).to_owned())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Notifications {
+    Table,
+    Id,
+    UserId,
+    ActorId,
+    NotificationType,
+    PostType,
+    PostId,
+    QuestionId,
+    ReputationChange,
+    ReadAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum NotificationPreferences {
+    Table,
+    Id,
+    UserId,
+    NotificationType,
+    Enabled,
+}
+
+#[derive(DeriveIden)]
+pub enum NotificationType {
+    Table,
+    #[sea_orm(iden = "answer")]
+    Answer,
+    #[sea_orm(iden = "comment")]
+    Comment,
+    #[sea_orm(iden = "reply")]
+    Reply,
+    #[sea_orm(iden = "mention")]
+    Mention,
+    #[sea_orm(iden = "accepted")]
+    Accepted,
+    #[sea_orm(iden = "reputation")]
+    Reputation,
+    #[sea_orm(iden = "edit")]
+    Edit,
+    #[sea_orm(iden = "solved")]
+    Solved,
+}
+
+impl NotificationType {
+    pub fn values() -> [NotificationType; 6] {
+        [
+            NotificationType::Answer,
+            NotificationType::Comment,
+            NotificationType::Reply,
+            NotificationType::Mention,
+            NotificationType::Accepted,
+            NotificationType::Reputation,
+        ]
+    }
+}
@@ -0,0 +1,225 @@
+use crate::m20250313_030734_create_users::Users;
+use crate::m20250313_030738_create_questions::Questions;
+use crate::m20250313_030800_create_tags::Tags;
+use crate::m20250409_090000_create_notifications::NotificationType;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let is_postgres = manager.get_database_backend() == DatabaseBackend::Postgres;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(QuestionFollows::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(QuestionFollows::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(QuestionFollows::UserId).integer().not_null())
+                    .col(ColumnDef::new(QuestionFollows::QuestionId).integer().not_null())
+                    .col(
+                        ColumnDef::new(QuestionFollows::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_question_follows_users")
+                            .from(QuestionFollows::Table, QuestionFollows::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_question_follows_questions")
+                            .from(QuestionFollows::Table, QuestionFollows::QuestionId)
+                            .to(Questions::Table, Questions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_question_follows_user_question")
+                            .col(QuestionFollows::UserId)
+                            .col(QuestionFollows::QuestionId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "INSERT INTO question_follows (user_id, question_id, created_at) \
+             SELECT user_id, id, created_at FROM questions",
+        )
+        .await?;
+        db.execute_unprepared(
+            "INSERT INTO question_follows (user_id, question_id, created_at) \
+             SELECT a.user_id, a.question_id, MIN(a.created_at) FROM answers a \
+             WHERE NOT EXISTS (SELECT 1 FROM question_follows f WHERE f.user_id = a.user_id AND f.question_id = a.question_id) \
+             GROUP BY a.user_id, a.question_id",
+        )
+        .await?;
+
+        if is_postgres {
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(TagPreference::Table)
+                        .values([TagPreference::Watched, TagPreference::Ignored])
+                        .to_owned(),
+                )
+                .await?;
+
+            for value in [NotificationType::Edit, NotificationType::Solved] {
+                manager
+                    .alter_type(
+                        Type::alter()
+                            .name(NotificationType::Table)
+                            .add_value(value)
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TagPreferences::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TagPreferences::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TagPreferences::UserId).integer().not_null())
+                    .col(ColumnDef::new(TagPreferences::TagId).integer().not_null())
+                    .col(
+                        ColumnDef::new(TagPreferences::Preference)
+                            .enumeration(TagPreference::Table, [TagPreference::Watched, TagPreference::Ignored])
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(TagPreferences::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_preferences_users")
+                            .from(TagPreferences::Table, TagPreferences::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_preferences_tags")
+                            .from(TagPreferences::Table, TagPreferences::TagId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_tag_preferences_user_tag")
+                            .col(TagPreferences::UserId)
+                            .col(TagPreferences::TagId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(TagDisplay::HideIgnoredTags)
+                            .boolean()
+                            .not_null()
+                            .default(false)
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(TagDisplay::HideIgnoredTags)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(TagPreferences::Table).to_owned())
+            .await?;
+
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .drop_type(Type::drop().name(TagPreference::Table).to_owned())
+                .await?;
+        }
+
+        manager
+            .get_connection()
+            .execute_unprepared("DELETE FROM notifications WHERE notification_type IN ('edit', 'solved')")
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(QuestionFollows::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TagDisplay {
+    HideIgnoredTags,
+}
+
+#[derive(DeriveIden)]
+pub enum QuestionFollows {
+    Table,
+    Id,
+    UserId,
+    QuestionId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum TagPreferences {
+    Table,
+    Id,
+    UserId,
+    TagId,
+    Preference,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum TagPreference {
+    Table,
+    #[sea_orm(iden = "watched")]
+    Watched,
+    #[sea_orm(iden = "ignored")]
+    Ignored,
+}
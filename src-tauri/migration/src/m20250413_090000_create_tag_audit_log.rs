@@ -0,0 +1,125 @@
+use crate::m20250313_030734_create_users::Users;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(TagAuditAction::Table)
+                        .values(TagAuditAction::values())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TagAuditLogs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TagAuditLogs::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TagAuditLogs::ModeratorId).integer().null())
+                    .col(
+                        ColumnDef::new(TagAuditLogs::Action)
+                            .enumeration(TagAuditAction::Table, TagAuditAction::values())
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(TagAuditLogs::TagId).integer().not_null())
+                    .col(ColumnDef::new(TagAuditLogs::TagName).string().not_null())
+                    .col(ColumnDef::new(TagAuditLogs::TargetTagId).integer().null())
+                    .col(ColumnDef::new(TagAuditLogs::OldValue).text().null())
+                    .col(ColumnDef::new(TagAuditLogs::NewValue).text().null())
+                    .col(
+                        ColumnDef::new(TagAuditLogs::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_audit_logs_moderators")
+                            .from(TagAuditLogs::Table, TagAuditLogs::ModeratorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_tag_audit_logs_tag")
+                    .table(TagAuditLogs::Table)
+                    .col(TagAuditLogs::TagId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TagAuditLogs::Table).to_owned())
+            .await?;
+
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .drop_type(Type::drop().name(TagAuditAction::Table).to_owned())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TagAuditLogs {
+    Table,
+    Id,
+    ModeratorId,
+    Action,
+    TagId,
+    TagName,
+    TargetTagId,
+    OldValue,
+    NewValue,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum TagAuditAction {
+    Table,
+    #[sea_orm(iden = "update")]
+    Update,
+    #[sea_orm(iden = "rename")]
+    Rename,
+    #[sea_orm(iden = "merge")]
+    Merge,
+    #[sea_orm(iden = "delete")]
+    Delete,
+}
+
+impl TagAuditAction {
+    pub fn values() -> [TagAuditAction; 4] {
+        [
+            TagAuditAction::Update,
+            TagAuditAction::Rename,
+            TagAuditAction::Merge,
+            TagAuditAction::Delete,
+        ]
+    }
+}
@@ -0,0 +1,206 @@
+use crate::m20250313_030734_create_users::Users;
+use crate::m20250313_030800_create_tags::Tags;
+use crate::m20250313_030821_create_votes::VoteType;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(SynonymStatus::Table)
+                        .values(SynonymStatus::values())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TagSynonyms::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TagSynonyms::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(TagSynonyms::SourceName)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(TagSynonyms::TargetTagId).integer().not_null())
+                    .col(ColumnDef::new(TagSynonyms::SuggestedBy).integer().null())
+                    .col(
+                        ColumnDef::new(TagSynonyms::Status)
+                            .enumeration(SynonymStatus::Table, SynonymStatus::values())
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(TagSynonyms::Score)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                    )
+                    .col(ColumnDef::new(TagSynonyms::ReviewedBy).integer().null())
+                    .col(
+                        ColumnDef::new(TagSynonyms::ReviewedAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .col(
+                        ColumnDef::new(TagSynonyms::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_synonyms_tags")
+                            .from(TagSynonyms::Table, TagSynonyms::TargetTagId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_synonyms_suggesters")
+                            .from(TagSynonyms::Table, TagSynonyms::SuggestedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_synonyms_reviewers")
+                            .from(TagSynonyms::Table, TagSynonyms::ReviewedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TagSynonymVotes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TagSynonymVotes::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TagSynonymVotes::SynonymId).integer().not_null())
+                    .col(ColumnDef::new(TagSynonymVotes::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(TagSynonymVotes::VoteType)
+                            .enumeration(VoteType::Table, [VoteType::Up, VoteType::Down])
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(TagSynonymVotes::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_synonym_votes_synonyms")
+                            .from(TagSynonymVotes::Table, TagSynonymVotes::SynonymId)
+                            .to(TagSynonyms::Table, TagSynonyms::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_synonym_votes_users")
+                            .from(TagSynonymVotes::Table, TagSynonymVotes::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_tag_synonym_votes_synonym_user")
+                            .col(TagSynonymVotes::SynonymId)
+                            .col(TagSynonymVotes::UserId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TagSynonymVotes::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(TagSynonyms::Table).to_owned())
+            .await?;
+
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .drop_type(Type::drop().name(SynonymStatus::Table).to_owned())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TagSynonyms {
+    Table,
+    Id,
+    SourceName,
+    TargetTagId,
+    SuggestedBy,
+    Status,
+    Score,
+    ReviewedBy,
+    ReviewedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum TagSynonymVotes {
+    Table,
+    Id,
+    SynonymId,
+    UserId,
+    VoteType,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum SynonymStatus {
+    Table,
+    #[sea_orm(iden = "pending")]
+    Pending,
+    #[sea_orm(iden = "approved")]
+    Approved,
+    #[sea_orm(iden = "rejected")]
+    Rejected,
+}
+
+impl SynonymStatus {
+    pub fn values() -> [SynonymStatus; 3] {
+        [
+            SynonymStatus::Pending,
+            SynonymStatus::Approved,
+            SynonymStatus::Rejected,
+        ]
+    }
+}
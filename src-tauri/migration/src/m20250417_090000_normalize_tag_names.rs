@@ -0,0 +1,234 @@
+use crate::m20250313_030800_create_tags::Tags;
+use crate::m20250313_030810_create_question_tags::QuestionTags;
+use crate::m20250411_090000_create_follows_and_tag_preferences::TagPreferences;
+use crate::m20250413_090000_create_tag_audit_log::{TagAuditAction, TagAuditLogs};
+use crate::m20250415_090000_create_tag_synonyms::TagSynonyms;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DatabaseBackend};
+use std::collections::BTreeMap;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const MAX_TAG_LENGTH: usize = 35;
+
+fn normalize_tag_name(value: &str) -> String {
+    let mut normalized = String::with_capacity(value.len());
+    for c in value.trim().chars().flat_map(char::to_lowercase) {
+        let c = if c.is_whitespace() || c == '_' { '-' } else { c };
+        if !(c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '.' | '+' | '#')) {
+            continue;
+        }
+        if normalized.is_empty() && !c.is_ascii_alphanumeric() {
+            continue;
+        }
+        if c == '-' && normalized.ends_with('-') {
+            continue;
+        }
+        normalized.push(c);
+    }
+    normalized.truncate(MAX_TAG_LENGTH);
+    while normalized.ends_with('-') {
+        normalized.pop();
+    }
+    normalized
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        let rows = db
+            .query_all(backend.build(
+                Query::select()
+                    .columns([Tags::Id, Tags::Name])
+                    .from(Tags::Table)
+                    .order_by(Tags::Id, Order::Asc),
+            ))
+            .await?;
+
+        let mut groups: BTreeMap<String, Vec<(i32, String)>> = BTreeMap::new();
+        for row in rows {
+            let id: i32 = row.try_get("", "id")?;
+            let name: String = row.try_get("", "name")?;
+            groups.entry(normalize_tag_name(&name)).or_default().push((id, name));
+        }
+
+        for (normalized, tags) in groups {
+            if normalized.is_empty() {
+                for (id, name) in tags {
+                    audit(manager, TagAuditAction::Delete, id, &name, None, Some(name.clone()), None).await?;
+                    detach(manager, id).await?;
+                }
+                continue;
+            }
+
+            let (keep_id, keep_name) = &tags[0];
+            for (id, name) in &tags[1..] {
+                let moved = count_questions(db, backend, *id).await?;
+                merge(manager, *id, *keep_id).await?;
+
+                let summary = format!("{} ({} questions moved)", normalized, moved);
+                audit(manager, TagAuditAction::Merge, *id, name, Some(*keep_id), Some(name.clone()), Some(summary)).await?;
+                detach(manager, *id).await?;
+            }
+
+            if *keep_name != normalized {
+                audit(
+                    manager,
+                    TagAuditAction::Rename,
+                    *keep_id,
+                    keep_name,
+                    None,
+                    Some(keep_name.clone()),
+                    Some(normalized.clone()),
+                )
+                .await?;
+
+                manager
+                    .exec_stmt(
+                        Query::update()
+                            .table(Tags::Table)
+                            .value(Tags::Name, normalized)
+                            .and_where(Expr::col(Tags::Id).eq(*keep_id))
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
+
+async fn merge(manager: &SchemaManager<'_>, source_id: i32, target_id: i32) -> Result<(), DbErr> {
+    manager
+        .exec_stmt(
+            Query::update()
+                .table(QuestionTags::Table)
+                .value(QuestionTags::TagId, target_id)
+                .and_where(Expr::col(QuestionTags::TagId).eq(source_id))
+                .and_where(
+                    Expr::col(QuestionTags::QuestionId).not_in_subquery(
+                        Query::select()
+                            .column(QuestionTags::QuestionId)
+                            .from(QuestionTags::Table)
+                            .and_where(Expr::col(QuestionTags::TagId).eq(target_id))
+                            .to_owned(),
+                    ),
+                )
+                .to_owned(),
+        )
+        .await?;
+
+    manager
+        .exec_stmt(
+            Query::update()
+                .table(TagPreferences::Table)
+                .value(TagPreferences::TagId, target_id)
+                .and_where(Expr::col(TagPreferences::TagId).eq(source_id))
+                .and_where(
+                    Expr::col(TagPreferences::UserId).not_in_subquery(
+                        Query::select()
+                            .column(TagPreferences::UserId)
+                            .from(TagPreferences::Table)
+                            .and_where(Expr::col(TagPreferences::TagId).eq(target_id))
+                            .to_owned(),
+                    ),
+                )
+                .to_owned(),
+        )
+        .await?;
+
+    manager
+        .exec_stmt(
+            Query::update()
+                .table(TagSynonyms::Table)
+                .value(TagSynonyms::TargetTagId, target_id)
+                .and_where(Expr::col(TagSynonyms::TargetTagId).eq(source_id))
+                .to_owned(),
+        )
+        .await
+}
+
+async fn detach(manager: &SchemaManager<'_>, tag_id: i32) -> Result<(), DbErr> {
+    for (table, column) in [
+        (QuestionTags::Table.into_iden(), QuestionTags::TagId.into_iden()),
+        (TagPreferences::Table.into_iden(), TagPreferences::TagId.into_iden()),
+        (TagSynonyms::Table.into_iden(), TagSynonyms::TargetTagId.into_iden()),
+    ] {
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(table)
+                    .and_where(Expr::col(column).eq(tag_id))
+                    .to_owned(),
+            )
+            .await?;
+    }
+
+    manager
+        .exec_stmt(
+            Query::delete()
+                .from_table(Tags::Table)
+                .and_where(Expr::col(Tags::Id).eq(tag_id))
+                .to_owned(),
+        )
+        .await
+}
+
+async fn count_questions<C: ConnectionTrait>(db: &C, backend: DatabaseBackend, tag_id: i32) -> Result<i64, DbErr> {
+    let row = db
+        .query_one(backend.build(
+            Query::select()
+                .expr_as(Expr::col(QuestionTags::QuestionId).count(), Alias::new("count"))
+                .from(QuestionTags::Table)
+                .and_where(Expr::col(QuestionTags::TagId).eq(tag_id)),
+        ))
+        .await?;
+
+    match row {
+        Some(row) => row.try_get("", "count"),
+        None => Ok(0),
+    }
+}
+
+async fn audit(
+    manager: &SchemaManager<'_>,
+    action: TagAuditAction,
+    tag_id: i32,
+    tag_name: &str,
+    target_tag_id: Option<i32>,
+    old_value: Option<String>,
+    new_value: Option<String>,
+) -> Result<(), DbErr> {
+    manager
+        .exec_stmt(
+            Query::insert()
+                .into_table(TagAuditLogs::Table)
+                .columns([
+                    TagAuditLogs::Action,
+                    TagAuditLogs::TagId,
+                    TagAuditLogs::TagName,
+                    TagAuditLogs::TargetTagId,
+                    TagAuditLogs::OldValue,
+                    TagAuditLogs::NewValue,
+                ])
+                .values_panic([
+                    Expr::val(action.to_string()).as_enum(TagAuditAction::Table),
+                    tag_id.into(),
+                    tag_name.into(),
+                    target_tag_id.into(),
+                    old_value.into(),
+                    new_value.into(),
+                ])
+                .to_owned(),
+        )
+        .await
+}
@@ -0,0 +1,131 @@
+use crate::m20250313_030734_create_users::Users;
+use crate::m20250313_030800_create_tags::Tags;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tags::Table)
+                    .rename_column(Tags::Description, TagWiki::Excerpt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tags::Table)
+                    .add_column(ColumnDef::new(TagWiki::Wiki).text().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TagRevisions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TagRevisions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TagRevisions::TagId).integer().not_null())
+                    .col(ColumnDef::new(TagRevisions::UserId).integer().null())
+                    .col(ColumnDef::new(TagRevisions::Revision).integer().not_null())
+                    .col(ColumnDef::new(TagRevisions::Excerpt).text().null())
+                    .col(ColumnDef::new(TagRevisions::Wiki).text().null())
+                    .col(ColumnDef::new(TagRevisions::Comment).string().null())
+                    .col(
+                        ColumnDef::new(TagRevisions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_revisions_tags")
+                            .from(TagRevisions::Table, TagRevisions::TagId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_revisions_users")
+                            .from(TagRevisions::Table, TagRevisions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_tag_revisions_tag_revision")
+                            .col(TagRevisions::TagId)
+                            .col(TagRevisions::Revision)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "INSERT INTO tag_revisions (tag_id, revision, excerpt) \
+                 SELECT id, 1, excerpt FROM tags WHERE excerpt IS NOT NULL",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TagRevisions::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tags::Table)
+                    .drop_column(TagWiki::Wiki)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tags::Table)
+                    .rename_column(TagWiki::Excerpt, Tags::Description)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TagWiki {
+    Excerpt,
+    Wiki,
+}
+
+#[derive(DeriveIden)]
+pub enum TagRevisions {
+    Table,
+    Id,
+    TagId,
+    UserId,
+    Revision,
+    Excerpt,
+    Wiki,
+    Comment,
+    CreatedAt,
+}
@@ -0,0 +1,56 @@
+use crate::m20250313_030810_create_question_tags::QuestionTags;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_question_tags_tag")
+                    .table(QuestionTags::Table)
+                    .col(QuestionTags::TagId)
+                    .to_owned(),
+            )
+            .await?;
+
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .get_connection()
+                .execute_unprepared("CREATE INDEX idx_tags_name_prefix ON tags (name text_pattern_ops)")
+                .await?;
+            manager
+                .get_connection()
+                .execute_unprepared("CREATE INDEX idx_tag_synonyms_source_prefix ON tag_synonyms (source_name text_pattern_ops)")
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .get_connection()
+                .execute_unprepared("DROP INDEX IF EXISTS idx_tag_synonyms_source_prefix")
+                .await?;
+            manager
+                .get_connection()
+                .execute_unprepared("DROP INDEX IF EXISTS idx_tags_name_prefix")
+                .await?;
+        }
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_question_tags_tag")
+                    .table(QuestionTags::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,117 @@
+use crate::m20250313_030738_create_questions::Questions;
+use crate::m20250405_090000_create_flags::PostType;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostLinks::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PostLinks::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PostLinks::PostType)
+                            .enumeration(PostType::Table, [PostType::Question, PostType::Answer, PostType::Comment])
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(PostLinks::PostId).integer().not_null())
+                    .col(ColumnDef::new(PostLinks::QuestionId).integer().not_null())
+                    .col(ColumnDef::new(PostLinks::LinkedQuestionId).integer().not_null())
+                    .col(
+                        ColumnDef::new(PostLinks::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_post_links_questions")
+                            .from(PostLinks::Table, PostLinks::QuestionId)
+                            .to(Questions::Table, Questions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_post_links_linked_questions")
+                            .from(PostLinks::Table, PostLinks::LinkedQuestionId)
+                            .to(Questions::Table, Questions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_post_links_post_linked")
+                            .col(PostLinks::PostType)
+                            .col(PostLinks::PostId)
+                            .col(PostLinks::LinkedQuestionId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_links_linked_question")
+                    .table(PostLinks::Table)
+                    .col(PostLinks::LinkedQuestionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            let db = manager.get_connection();
+            db.execute_unprepared(
+                "INSERT INTO post_links (post_type, post_id, question_id, linked_question_id) \
+                 SELECT DISTINCT 'question'::post_type, q.id, q.id, l.id::int \
+                 FROM questions q CROSS JOIN LATERAL ( \
+                     SELECT m[1]::numeric AS id \
+                     FROM regexp_matches(q.content, '(?:^|[[:space:](<\"''])/(?:questions|q)/([0-9]+)', 'g') m \
+                 ) l \
+                 WHERE l.id <= 2147483647 AND l.id <> q.id AND EXISTS (SELECT 1 FROM questions t WHERE t.id = l.id)",
+            )
+            .await?;
+            db.execute_unprepared(
+                "INSERT INTO post_links (post_type, post_id, question_id, linked_question_id) \
+                 SELECT DISTINCT 'answer'::post_type, a.id, a.question_id, l.id::int \
+                 FROM answers a CROSS JOIN LATERAL ( \
+                     SELECT m[1]::numeric AS id \
+                     FROM regexp_matches(a.content, '(?:^|[[:space:](<\"''])/(?:questions|q)/([0-9]+)', 'g') m \
+                 ) l \
+                 WHERE l.id <= 2147483647 AND l.id <> a.question_id AND EXISTS (SELECT 1 FROM questions t WHERE t.id = l.id)",
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostLinks::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum PostLinks {
+    Table,
+    Id,
+    PostType,
+    PostId,
+    QuestionId,
+    LinkedQuestionId,
+    CreatedAt,
+}
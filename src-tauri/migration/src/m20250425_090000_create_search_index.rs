@@ -0,0 +1,103 @@
+use crate::m20250313_030738_create_questions::Questions;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SearchDocuments::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SearchDocuments::QuestionId)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SearchDocuments::TitleLength).integer().not_null())
+                    .col(ColumnDef::new(SearchDocuments::BodyLength).integer().not_null())
+                    .col(
+                        ColumnDef::new(SearchDocuments::IndexedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_search_documents_questions")
+                            .from(SearchDocuments::Table, SearchDocuments::QuestionId)
+                            .to(Questions::Table, Questions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SearchTerms::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(SearchTerms::QuestionId).integer().not_null())
+                    .col(ColumnDef::new(SearchTerms::Term).string_len(64).not_null())
+                    .col(ColumnDef::new(SearchTerms::TitleFrequency).integer().not_null())
+                    .col(ColumnDef::new(SearchTerms::BodyFrequency).integer().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(SearchTerms::QuestionId)
+                            .col(SearchTerms::Term)
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_search_terms_search_documents")
+                            .from(SearchTerms::Table, SearchTerms::QuestionId)
+                            .to(SearchDocuments::Table, SearchDocuments::QuestionId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_search_terms_term")
+                    .table(SearchTerms::Table)
+                    .col(SearchTerms::Term)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SearchTerms::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(SearchDocuments::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SearchDocuments {
+    Table,
+    QuestionId,
+    TitleLength,
+    BodyLength,
+    IndexedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum SearchTerms {
+    Table,
+    QuestionId,
+    Term,
+    TitleFrequency,
+    BodyFrequency,
+}
@@ -0,0 +1,45 @@
+use crate::m20250313_030738_create_questions::Questions;
+use crate::m20250313_030743_create_answers::Answers;
+use crate::m20250313_030756_create_comments::Comments;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [Questions::Table.into_iden(), Answers::Table.into_iden(), Comments::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(ColumnDef::new(Rendering::RenderedHtml).text().null())
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [Questions::Table.into_iden(), Answers::Table.into_iden(), Comments::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(Rendering::RenderedHtml)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Rendering {
+    RenderedHtml,
+}
@@ -0,0 +1,50 @@
+use crate::m20250313_030734_create_users::Users;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(ColumnDef::new(TwoFactorThrottling::TotpLastStep).big_integer().null())
+                    .add_column(
+                        ColumnDef::new(TwoFactorThrottling::TwoFactorFailedAttempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(TwoFactorThrottling::TwoFactorLockedUntil)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(TwoFactorThrottling::TotpLastStep)
+                    .drop_column(TwoFactorThrottling::TwoFactorFailedAttempts)
+                    .drop_column(TwoFactorThrottling::TwoFactorLockedUntil)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TwoFactorThrottling {
+    TotpLastStep,
+    TwoFactorFailedAttempts,
+    TwoFactorLockedUntil,
+}
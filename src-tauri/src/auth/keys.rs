@@ -0,0 +1,197 @@
+use jsonwebtoken::{
+    decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::{env, fs, path::Path};
+
+const DEFAULT_KEY_ID: &str = "default";
+const DEVELOPMENT_SECRET: &str = "queueoverflow-development-secret";
+const KNOWN_DEFAULT_SECRETS: &[&str] = &[
+    DEVELOPMENT_SECRET,
+    "kolin123",
+    "your-256-bit-secret",
+    "replace-with-a-long-random-secret",
+];
+
+#[derive(Debug, Deserialize)]
+struct KeyFile {
+    active: String,
+    keys: Vec<KeyEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KeyEntry {
+    kid: String,
+    algorithm: String,
+    secret: Option<String>,
+    private_key_path: Option<String>,
+    public_key_path: Option<String>,
+}
+
+struct SigningKey {
+    kid: String,
+    algorithm: Algorithm,
+    key: EncodingKey,
+}
+
+struct VerificationKey {
+    algorithm: Algorithm,
+    key: DecodingKey,
+}
+
+pub struct KeyManager {
+    signing: SigningKey,
+    verification: HashMap<String, VerificationKey>,
+}
+
+impl KeyManager {
+    pub fn from_env() -> Result<Self, String> {
+        if let Ok(path) = env::var("JWT_KEYS_FILE") {
+            return Self::from_file(Path::new(&path));
+        }
+
+        let secret = match env::var("JWT_SECRET") {
+            Ok(secret) if !secret.trim().is_empty() => secret,
+            _ => {
+                if cfg!(not(debug_assertions)) {
+                    return Err("JWT_SECRET or JWT_KEYS_FILE must be set in release builds".to_string());
+                }
+                println!("JWT_SECRET not set in environment. Using development secret.");
+                DEVELOPMENT_SECRET.to_string()
+            }
+        };
+
+        if cfg!(not(debug_assertions)) && KNOWN_DEFAULT_SECRETS.contains(&secret.as_str()) {
+            return Err("JWT_SECRET is set to a default value; refusing to start".to_string());
+        }
+
+        let kid = env::var("JWT_KEY_ID").unwrap_or_else(|_| DEFAULT_KEY_ID.to_string());
+        let mut verification = HashMap::new();
+        verification.insert(
+            kid.clone(),
+            VerificationKey {
+                algorithm: Algorithm::HS256,
+                key: DecodingKey::from_secret(secret.as_bytes()),
+            },
+        );
+
+        Ok(Self {
+            signing: SigningKey {
+                kid,
+                algorithm: Algorithm::HS256,
+                key: EncodingKey::from_secret(secret.as_bytes()),
+            },
+            verification,
+        })
+    }
+
+    fn from_file(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read key file {}: {}", path.display(), e))?;
+        let file: KeyFile = toml::from_str(&contents)
+            .map_err(|e| format!("Failed to parse key file {}: {}", path.display(), e))?;
+        let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+        let mut signing = None;
+        let mut verification = HashMap::new();
+
+        for entry in file.keys {
+            let algorithm = match entry.algorithm.as_str() {
+                "HS256" => Algorithm::HS256,
+                "RS256" => Algorithm::RS256,
+                "EdDSA" => Algorithm::EdDSA,
+                other => return Err(format!("Unsupported JWT algorithm for key {}: {}", entry.kid, other)),
+            };
+
+            let (decoding, encoding) = match algorithm {
+                Algorithm::HS256 => {
+                    let secret = entry
+                        .secret
+                        .as_deref()
+                        .ok_or_else(|| format!("Key {} is missing a secret", entry.kid))?;
+
+                    if cfg!(not(debug_assertions)) && KNOWN_DEFAULT_SECRETS.contains(&secret) {
+                        return Err(format!("Key {} uses a default secret; refusing to start", entry.kid));
+                    }
+
+                    (
+                        DecodingKey::from_secret(secret.as_bytes()),
+                        Some(EncodingKey::from_secret(secret.as_bytes())),
+                    )
+                }
+                _ => {
+                    let public_pem = read_pem(base_dir, entry.public_key_path.as_deref(), &entry.kid, "public_key_path")?;
+                    let decoding = match algorithm {
+                        Algorithm::RS256 => DecodingKey::from_rsa_pem(&public_pem),
+                        _ => DecodingKey::from_ed_pem(&public_pem),
+                    }
+                    .map_err(|e| format!("Invalid public key for {}: {}", entry.kid, e))?;
+
+                    let encoding = match entry.private_key_path.as_deref() {
+                        Some(private_path) => {
+                            let private_pem = read_pem(base_dir, Some(private_path), &entry.kid, "private_key_path")?;
+                            let key = match algorithm {
+                                Algorithm::RS256 => EncodingKey::from_rsa_pem(&private_pem),
+                                _ => EncodingKey::from_ed_pem(&private_pem),
+                            }
+                            .map_err(|e| format!("Invalid private key for {}: {}", entry.kid, e))?;
+                            Some(key)
+                        }
+                        None => None,
+                    };
+
+                    (decoding, encoding)
+                }
+            };
+
+            if entry.kid == file.active {
+                let key = encoding.ok_or_else(|| format!("Active key {} has no private key", entry.kid))?;
+                signing = Some(SigningKey {
+                    kid: entry.kid.clone(),
+                    algorithm,
+                    key,
+                });
+            }
+
+            verification.insert(entry.kid, VerificationKey { algorithm, key: decoding });
+        }
+
+        let signing = signing.ok_or_else(|| format!("Active key {} is not defined in key file", file.active))?;
+
+        Ok(Self { signing, verification })
+    }
+
+    pub fn sign<T: Serialize>(&self, claims: &T) -> Result<String, String> {
+        let mut header = Header::new(self.signing.algorithm);
+        header.kid = Some(self.signing.kid.clone());
+
+        encode(&header, claims, &self.signing.key).map_err(|e| format!("Failed to sign token: {}", e))
+    }
+
+    pub fn verify<T: DeserializeOwned>(&self, token: &str) -> Result<T, String> {
+        let header = decode_header(token).map_err(|_| "Token is malformed".to_string())?;
+        let kid = header.kid.unwrap_or_else(|| DEFAULT_KEY_ID.to_string());
+
+        let key = self
+            .verification
+            .get(&kid)
+            .ok_or_else(|| "Token was signed with an unknown key".to_string())?;
+
+        if header.alg != key.algorithm {
+            return Err("Token algorithm does not match its key".to_string());
+        }
+
+        decode::<T>(token, &key.key, &Validation::new(key.algorithm))
+            .map(|data| data.claims)
+            .map_err(|_| "Token is invalid or has expired".to_string())
+    }
+}
+
+fn read_pem(base_dir: &Path, path: Option<&str>, kid: &str, field: &str) -> Result<Vec<u8>, String> {
+    let path = path.ok_or_else(|| format!("Key {} is missing {}", kid, field))?;
+    let path = base_dir.join(path);
+
+    fs::read(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
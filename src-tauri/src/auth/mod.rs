@@ -0,0 +1,88 @@
+pub mod keys;
+pub mod password;
+pub mod secrets;
+pub mod session;
+pub mod totp;
+
+use sea_orm::EntityTrait;
+use serde::{Deserialize, Serialize};
+
+use crate::repository::personal_access_token_repository::{
+    PersonalAccessTokenRepository, TOKEN_PREFIX,
+};
+use crate::entities::users;
+use crate::error::{AppError, Result};
+use crate::AppState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Scope {
+    #[serde(rename = "read")]
+    Read,
+    #[serde(rename = "write:questions")]
+    WriteQuestions,
+    #[serde(rename = "write:votes")]
+    WriteVotes,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl Scope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::Read => "read",
+            Scope::WriteQuestions => "write:questions",
+            Scope::WriteVotes => "write:votes",
+            Scope::Admin => "admin",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Scope> {
+        match value {
+            "read" => Some(Scope::Read),
+            "write:questions" => Some(Scope::WriteQuestions),
+            "write:votes" => Some(Scope::WriteVotes),
+            "admin" => Some(Scope::Admin),
+            _ => None,
+        }
+    }
+}
+
+pub async fn authorize(state: &AppState, token: &str, scope: Scope) -> Result<i32> {
+    if token.starts_with(TOKEN_PREFIX) {
+        let repo = PersonalAccessTokenRepository::new(state.db.clone());
+        let (user_id, scopes) = repo.authenticate(token).await?;
+
+        if !scopes.contains(&scope) && !scopes.contains(&Scope::Admin) {
+            return Err(AppError::Forbidden(format!("Token is missing the {} scope", scope.as_str())));
+        }
+
+        return Ok(user_id);
+    }
+
+    session::verify_session_token(&state.keys, token)
+}
+
+pub fn authorize_session(state: &AppState, token: &str) -> Result<i32> {
+    if token.starts_with(TOKEN_PREFIX) {
+        return Err(AppError::Forbidden(
+            "This action requires signing in; personal access tokens are not accepted".to_string(),
+        ));
+    }
+
+    session::verify_session_token(&state.keys, token)
+}
+
+pub async fn authorize_moderator(state: &AppState, token: &str) -> Result<i32> {
+    let user_id = authorize(state, token, Scope::Admin).await?;
+
+    let user = users::Entity::find_by_id(user_id)
+        .one(&state.db)
+        .await?
+        .ok_or_else(|| AppError::Unauthorized("User no longer exists".to_string()))?;
+
+    if !user.is_moderator {
+        return Err(AppError::Forbidden("Only moderators can perform this action".to_string()));
+    }
+
+    Ok(user_id)
+}
@@ -0,0 +1,103 @@
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Algorithm, Argon2, Params, Version,
+};
+use rand_core::OsRng;
+
+use crate::config::env_or;
+
+const PASSWORD_BLOCKLIST: &str = include_str!("password_blocklist.txt");
+const DEFAULT_MIN_LENGTH: usize = 8;
+const MAX_LENGTH: usize = 128;
+
+#[derive(Debug, Clone)]
+pub struct PasswordConfig {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+    pub min_length: usize,
+}
+
+impl PasswordConfig {
+    pub fn from_env() -> Self {
+        Self {
+            memory_kib: env_or("ARGON2_MEMORY_KIB", Params::DEFAULT_M_COST),
+            iterations: env_or("ARGON2_ITERATIONS", Params::DEFAULT_T_COST),
+            parallelism: env_or("ARGON2_PARALLELISM", Params::DEFAULT_P_COST),
+            min_length: env_or("PASSWORD_MIN_LENGTH", DEFAULT_MIN_LENGTH),
+        }
+    }
+
+    fn params(&self) -> Result<Params, String> {
+        Params::new(self.memory_kib, self.iterations, self.parallelism, None)
+            .map_err(|e| format!("Invalid Argon2 parameters: {}", e))
+    }
+
+    fn hasher(&self) -> Result<Argon2<'static>, String> {
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params()?))
+    }
+
+    pub fn hash_password(&self, password: &str) -> Result<String, String> {
+        let salt = SaltString::generate(&mut OsRng);
+
+        self.hasher()?
+            .hash_password(password.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+            .map_err(|e| format!("Password hashing error: {}", e))
+    }
+
+    pub fn verify_password(&self, password_hash: &str, password: &str) -> Result<(), String> {
+        let parsed_hash = PasswordHash::new(password_hash)
+            .map_err(|e| format!("Failed to parse password hash: {}", e))?;
+
+        Argon2::default()
+            .verify_password(password.as_bytes(), &parsed_hash)
+            .map_err(|_| "Invalid password".to_string())
+    }
+
+    pub fn needs_rehash(&self, password_hash: &str) -> bool {
+        let parsed_hash = match PasswordHash::new(password_hash) {
+            Ok(hash) => hash,
+            Err(_) => return true,
+        };
+
+        if parsed_hash.algorithm != Algorithm::Argon2id.ident() {
+            return true;
+        }
+
+        match Params::try_from(&parsed_hash) {
+            Ok(params) => {
+                params.m_cost() < self.memory_kib
+                    || params.t_cost() < self.iterations
+                    || params.p_cost() < self.parallelism
+            }
+            Err(_) => true,
+        }
+    }
+
+    pub fn check_policy(&self, password: &str, username: &str, email: &str) -> Result<(), String> {
+        let length = password.chars().count();
+
+        if length < self.min_length {
+            return Err(format!("Password must be at least {} characters long", self.min_length));
+        }
+
+        if length > MAX_LENGTH {
+            return Err(format!("Password must be at most {} characters long", MAX_LENGTH));
+        }
+
+        let lowered = password.to_lowercase();
+        let email = email.trim().to_lowercase();
+        let email_local = email.split('@').next().unwrap_or_default();
+
+        if lowered == username.trim().to_lowercase() || lowered == email || lowered == email_local {
+            return Err("Password must not match your username or email".to_string());
+        }
+
+        if PASSWORD_BLOCKLIST.lines().any(|blocked| blocked == lowered) {
+            return Err("Password is on the blocklist of frequently used passwords, please choose another one".to_string());
+        }
+
+        Ok(())
+    }
+}
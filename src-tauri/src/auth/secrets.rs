@@ -0,0 +1,12 @@
+use rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+pub fn random_secret(byte_len: usize) -> String {
+    let mut bytes = vec![0u8; byte_len];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+pub fn hash_secret(secret: &str) -> String {
+    hex::encode(Sha256::digest(secret.as_bytes()))
+}
@@ -0,0 +1,62 @@
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::auth::keys::KeyManager;
+use crate::error::{AppError, Result};
+
+const TWO_FACTOR_PURPOSE: &str = "two_factor";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SessionClaims {
+    sub: String,
+    exp: usize,
+    iat: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    purpose: Option<String>,
+}
+
+pub fn issue_session_token(keys: &KeyManager, user_id: i32) -> std::result::Result<String, String> {
+    issue(keys, user_id, Duration::days(7), None)
+}
+
+pub fn issue_challenge_token(keys: &KeyManager, user_id: i32) -> std::result::Result<String, String> {
+    issue(keys, user_id, Duration::minutes(5), Some(TWO_FACTOR_PURPOSE))
+}
+
+pub fn verify_session_token(keys: &KeyManager, token: &str) -> Result<i32> {
+    verify(keys, token, None)
+        .map_err(|_| AppError::Unauthorized("Session is invalid or has expired".to_string()))
+}
+
+pub fn verify_challenge_token(keys: &KeyManager, token: &str) -> Result<i32> {
+    verify(keys, token, Some(TWO_FACTOR_PURPOSE))
+        .map_err(|_| AppError::Unauthorized("Login challenge is invalid or has expired".to_string()))
+}
+
+fn issue(
+    keys: &KeyManager,
+    user_id: i32,
+    lifetime: Duration,
+    purpose: Option<&str>,
+) -> std::result::Result<String, String> {
+    let now = Utc::now();
+
+    let claims = SessionClaims {
+        sub: user_id.to_string(),
+        exp: (now + lifetime).timestamp() as usize,
+        iat: now.timestamp() as usize,
+        purpose: purpose.map(str::to_string),
+    };
+
+    keys.sign(&claims)
+}
+
+fn verify(keys: &KeyManager, token: &str, purpose: Option<&str>) -> std::result::Result<i32, String> {
+    let claims = keys.verify::<SessionClaims>(token)?;
+
+    if claims.purpose.as_deref() != purpose {
+        return Err("Token purpose mismatch".to_string());
+    }
+
+    claims.sub.parse().map_err(|_| "Token subject is invalid".to_string())
+}
@@ -0,0 +1,57 @@
+use rand_core::{OsRng, RngCore};
+use std::time::{SystemTime, UNIX_EPOCH};
+use totp_rs::{Algorithm, Secret, TOTP};
+
+const ISSUER: &str = "QueueOverflow";
+const SECRET_BYTES: usize = 20;
+const DIGITS: usize = 6;
+const SKEW: u8 = 1;
+const STEP_SECONDS: u64 = 30;
+
+pub fn generate_secret() -> String {
+    let mut bytes = vec![0u8; SECRET_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    Secret::Raw(bytes).to_encoded().to_string()
+}
+
+fn build(secret: &str, account_name: &str) -> Result<TOTP, String> {
+    let bytes = Secret::Encoded(secret.to_string())
+        .to_bytes()
+        .map_err(|e| format!("Invalid TOTP secret: {}", e))?;
+
+    TOTP::new(
+        Algorithm::SHA1,
+        DIGITS,
+        SKEW,
+        STEP_SECONDS,
+        bytes,
+        Some(ISSUER.to_string()),
+        account_name.to_string(),
+    )
+    .map_err(|e| format!("Invalid TOTP configuration: {}", e))
+}
+
+pub fn provisioning_uri(secret: &str, account_name: &str) -> Result<String, String> {
+    Ok(build(secret, account_name)?.get_url())
+}
+
+pub fn verify_code(secret: &str, account_name: &str, code: &str) -> Result<Option<u64>, String> {
+    let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if code.len() != DIGITS || !code.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(None);
+    }
+
+    let totp = TOTP {
+        skew: 0,
+        ..build(secret, account_name)?
+    };
+    let current_step = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("System clock error: {}", e))?
+        .as_secs()
+        / STEP_SECONDS;
+
+    Ok((current_step.saturating_sub(u64::from(SKEW))..=current_step + u64::from(SKEW))
+        .find(|step| totp.check(&code, step * STEP_SECONDS)))
+}
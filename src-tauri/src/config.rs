@@ -0,0 +1,69 @@
+use std::env;
+
+const DEFAULT_COMMENT_MAX_DEPTH: i32 = 3;
+const DEFAULT_COMMENT_FLAG_HIDE_THRESHOLD: u64 = 3;
+const DEFAULT_REVIEW_MIN_REPUTATION: i32 = 500;
+const DEFAULT_REVIEW_CONSENSUS: u64 = 3;
+const DEFAULT_REVIEW_LATE_ANSWER_DAYS: i64 = 30;
+const DEFAULT_TAG_CREATE_MIN_REPUTATION: i32 = 1500;
+const DEFAULT_TAG_SYNONYM_MIN_REPUTATION: i32 = 2500;
+const DEFAULT_TAG_WIKI_EDIT_MIN_REPUTATION: i32 = 2000;
+const DEFAULT_TAG_SYNONYM_APPROVAL_SCORE: i32 = 4;
+
+#[derive(Debug, Clone)]
+pub struct CommentConfig {
+    pub max_depth: i32,
+    pub flag_hide_threshold: u64,
+}
+
+impl CommentConfig {
+    pub fn from_env() -> Self {
+        Self {
+            max_depth: env_or("COMMENT_MAX_DEPTH", DEFAULT_COMMENT_MAX_DEPTH).max(0),
+            flag_hide_threshold: env_or("COMMENT_FLAG_HIDE_THRESHOLD", DEFAULT_COMMENT_FLAG_HIDE_THRESHOLD).max(1),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReviewConfig {
+    pub min_reputation: i32,
+    pub consensus: u64,
+    pub late_answer_days: i64,
+}
+
+impl ReviewConfig {
+    pub fn from_env() -> Self {
+        Self {
+            min_reputation: env_or("REVIEW_MIN_REPUTATION", DEFAULT_REVIEW_MIN_REPUTATION),
+            consensus: env_or("REVIEW_CONSENSUS", DEFAULT_REVIEW_CONSENSUS).max(1),
+            late_answer_days: env_or("REVIEW_LATE_ANSWER_DAYS", DEFAULT_REVIEW_LATE_ANSWER_DAYS).max(0),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TagConfig {
+    pub create_min_reputation: i32,
+    pub synonym_min_reputation: i32,
+    pub synonym_approval_score: i32,
+    pub wiki_edit_min_reputation: i32,
+}
+
+impl TagConfig {
+    pub fn from_env() -> Self {
+        Self {
+            create_min_reputation: env_or("TAG_CREATE_MIN_REPUTATION", DEFAULT_TAG_CREATE_MIN_REPUTATION),
+            synonym_min_reputation: env_or("TAG_SYNONYM_MIN_REPUTATION", DEFAULT_TAG_SYNONYM_MIN_REPUTATION),
+            synonym_approval_score: env_or("TAG_SYNONYM_APPROVAL_SCORE", DEFAULT_TAG_SYNONYM_APPROVAL_SCORE).max(1),
+            wiki_edit_min_reputation: env_or("TAG_WIKI_EDIT_MIN_REPUTATION", DEFAULT_TAG_WIKI_EDIT_MIN_REPUTATION),
+        }
+    }
+}
+
+pub fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    env::var(key)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
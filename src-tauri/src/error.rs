@@ -1,35 +1,159 @@
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fmt;
-use sea_orm::DbErr;
+use sea_orm::{DbErr, SqlErr};
+use validator::{ValidationErrors, ValidationErrorsKind};
 
-#[derive(Debug, Serialize)]
+pub type FieldErrors = BTreeMap<String, Vec<String>>;
+
+#[derive(Debug)]
 pub enum AppError {
+    Validation(FieldErrors),
+    Unauthorized(String),
+    Forbidden(String),
     NotFound(String),
-    Database(String),
+    Conflict(String),
+    RateLimited(String),
     Internal(String),
 }
 
-impl fmt::Display for AppError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+#[derive(Serialize)]
+struct ErrorPayload<'a> {
+    code: &'static str,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fields: Option<&'a FieldErrors>,
+}
+
+impl AppError {
+    pub fn validation(field: &str, message: impl Into<String>) -> Self {
+        let mut fields = FieldErrors::new();
+        fields.insert(field.to_string(), vec![message.into()]);
+        AppError::Validation(fields)
+    }
+
+    pub fn code(&self) -> &'static str {
         match self {
-            AppError::NotFound(msg) => write!(f, "Not found: {}", msg),
-            AppError::Database(msg) => write!(f, "Database error: {}", msg),
-            AppError::Internal(msg) => write!(f, "Internal error: {}", msg),
+            AppError::Validation(_) => "validation_failed",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::NotFound(_) => "not_found",
+            AppError::Conflict(_) => "conflict",
+            AppError::RateLimited(_) => "rate_limited",
+            AppError::Internal(_) => "internal",
         }
     }
+
+    pub fn message(&self) -> String {
+        match self {
+            AppError::Validation(fields) => {
+                let count: usize = fields.values().map(Vec::len).sum();
+                if count == 1 {
+                    fields.values().flatten().next().cloned().unwrap_or_default()
+                } else {
+                    format!("{} fields are invalid", fields.len())
+                }
+            }
+            AppError::Unauthorized(msg)
+            | AppError::Forbidden(msg)
+            | AppError::NotFound(msg)
+            | AppError::Conflict(msg)
+            | AppError::RateLimited(msg)
+            | AppError::Internal(msg) => msg.clone(),
+        }
+    }
+}
+
+impl Serialize for AppError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let fields = match self {
+            AppError::Validation(fields) => Some(fields),
+            _ => None,
+        };
+
+        ErrorPayload {
+            code: self.code(),
+            message: self.message(),
+            fields,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code(), self.message())
+    }
 }
 
 impl std::error::Error for AppError {}
 
 impl From<DbErr> for AppError {
     fn from(err: DbErr) -> Self {
-        AppError::Database(err.to_string())
+        match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => {
+                return AppError::Conflict("A record with the same values already exists".to_string());
+            }
+            Some(SqlErr::ForeignKeyConstraintViolation(_)) => {
+                return AppError::Conflict("The record references data that does not exist".to_string());
+            }
+            _ => {}
+        }
+
+        match err {
+            DbErr::RecordNotFound(msg) => AppError::NotFound(msg),
+            err => {
+                println!("Database error: {}", err);
+                AppError::Internal("A database error occurred".to_string())
+            }
+        }
+    }
+}
+
+impl From<ValidationErrors> for AppError {
+    fn from(errors: ValidationErrors) -> Self {
+        let mut fields = FieldErrors::new();
+        collect_validation_errors(&mut fields, None, &errors);
+        AppError::Validation(fields)
+    }
+}
+
+fn collect_validation_errors(fields: &mut FieldErrors, prefix: Option<&str>, errors: &ValidationErrors) {
+    for (field, kind) in errors.errors() {
+        let path = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, field),
+            None => field.to_string(),
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                let messages = fields.entry(path).or_default();
+                for error in errors {
+                    messages.push(
+                        error
+                            .message
+                            .as_ref()
+                            .map(|m| m.to_string())
+                            .unwrap_or_else(|| format!("Invalid value ({})", error.code)),
+                    );
+                }
+            }
+            ValidationErrorsKind::Struct(errors) => {
+                collect_validation_errors(fields, Some(&path), errors);
+            }
+            ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    collect_validation_errors(fields, Some(&format!("{}[{}]", path, index)), errors);
+                }
+            }
+        }
     }
 }
 
 impl From<std::io::Error> for AppError {
     fn from(err: std::io::Error) -> Self {
-        AppError::Internal(err.to_string())
+        println!("I/O error: {}", err);
+        AppError::Internal("An internal error occurred".to_string())
     }
 }
 
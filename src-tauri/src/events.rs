@@ -0,0 +1,147 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+use tauri::{AppHandle, Emitter, EventTarget, Runtime};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::entities::sea_orm_active_enums::TargetType;
+
+pub const ACTIVITY_EVENT: &str = "question-activity";
+
+const CHANNEL_CAPACITY: usize = 256;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActivityEvent {
+    AnswerCreated {
+        question_id: i32,
+        answer_id: i32,
+    },
+    AnswerAccepted {
+        question_id: i32,
+        answer_id: i32,
+    },
+    VoteChanged {
+        question_id: i32,
+        target_id: i32,
+        target_type: TargetType,
+        total: i64,
+    },
+    CommentCreated {
+        question_id: i32,
+        comment_id: i32,
+        target_id: i32,
+        target_type: TargetType,
+    },
+    CommentEdited {
+        question_id: i32,
+        comment_id: i32,
+        target_id: i32,
+        target_type: TargetType,
+    },
+    CommentDeleted {
+        question_id: i32,
+        comment_id: i32,
+        target_id: i32,
+        target_type: TargetType,
+    },
+    QuestionClosed {
+        question_id: i32,
+    },
+    QuestionReopened {
+        question_id: i32,
+    },
+}
+
+impl ActivityEvent {
+    pub fn question_id(&self) -> i32 {
+        match self {
+            ActivityEvent::AnswerCreated { question_id, .. }
+            | ActivityEvent::AnswerAccepted { question_id, .. }
+            | ActivityEvent::VoteChanged { question_id, .. }
+            | ActivityEvent::CommentCreated { question_id, .. }
+            | ActivityEvent::CommentEdited { question_id, .. }
+            | ActivityEvent::CommentDeleted { question_id, .. }
+            | ActivityEvent::QuestionClosed { question_id }
+            | ActivityEvent::QuestionReopened { question_id } => *question_id,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<ActivityEvent>,
+    subscriptions: Arc<RwLock<HashMap<String, HashSet<i32>>>>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            sender,
+            subscriptions: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub fn publish(&self, event: ActivityEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    pub fn subscribe(&self, window: &str, question_id: i32) {
+        let mut subscriptions = self.subscriptions.write().unwrap_or_else(|e| e.into_inner());
+        subscriptions.entry(window.to_string()).or_default().insert(question_id);
+    }
+
+    pub fn unsubscribe(&self, window: &str, question_id: i32) {
+        let mut subscriptions = self.subscriptions.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(questions) = subscriptions.get_mut(window) {
+            questions.remove(&question_id);
+            if questions.is_empty() {
+                subscriptions.remove(window);
+            }
+        }
+    }
+
+    pub fn remove_window(&self, window: &str) {
+        let mut subscriptions = self.subscriptions.write().unwrap_or_else(|e| e.into_inner());
+        subscriptions.remove(window);
+    }
+
+    pub fn forward_to_windows<R: Runtime>(&self, app: AppHandle<R>) {
+        let mut receiver = self.sender.subscribe();
+        let bus = self.clone();
+
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => {
+                        for window in bus.subscribers(event.question_id()) {
+                            if let Err(e) = app.emit_to(EventTarget::webview_window(window.clone()), ACTIVITY_EVENT, event.clone()) {
+                                println!("Failed to emit activity to window {}: {}", window, e);
+                            }
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        println!("Activity forwarder fell behind and skipped {} events", skipped);
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    fn subscribers(&self, question_id: i32) -> Vec<String> {
+        let subscriptions = self.subscriptions.read().unwrap_or_else(|e| e.into_inner());
+        subscriptions
+            .iter()
+            .filter(|(_, questions)| questions.contains(&question_id))
+            .map(|(window, _)| window.clone())
+            .collect()
+    }
+}
@@ -0,0 +1,30 @@
+use tauri::{State, WebviewWindow};
+use crate::repository::question_repository::QuestionRepository;
+use crate::error::Result;
+use crate::AppState;
+
+#[tauri::command]
+pub async fn subscribe_to_question(
+    state: State<'_, AppState>,
+    window: WebviewWindow,
+    question_id: i32,
+) -> Result<()> {
+    let repo = QuestionRepository::new(state.db.clone(), state.events.clone());
+
+    repo.get_question_by_id(question_id).await?;
+
+    state.events.subscribe(window.label(), question_id);
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn unsubscribe_from_question(
+    state: State<'_, AppState>,
+    window: WebviewWindow,
+    question_id: i32,
+) -> Result<()> {
+    state.events.unsubscribe(window.label(), question_id);
+
+    Ok(())
+}
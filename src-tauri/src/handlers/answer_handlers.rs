@@ -1,52 +1,52 @@
 use tauri::State;
+use crate::auth::{authorize, Scope};
+use crate::error::Result;
 use crate::models::answer_models::*;
 use crate::repository::answer_repository::AnswerRepository;
 use crate::AppState;
+use validator::Validate;
 
 #[tauri::command]
 pub async fn create_answer(
     state: State<'_, AppState>,
-    user_id: i32,
+    token: String,
     request: CreateAnswerRequest,
-) -> Result<AnswerResponse, String> {
-    if request.content.trim().is_empty() {
-        return Err("Answer content cannot be empty.".into());
-    }
+) -> Result<AnswerResponse> {
+    request.validate()?;
 
-    let repo = AnswerRepository::new(state.db.clone());
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
 
-    match repo.create_answer(
+    let repo = AnswerRepository::new(state.db.clone(), state.events.clone());
+
+    let answer = repo.create_answer(
         request.question_id,
         user_id,
         request.content,
-    ).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+        &state.reviews,
+    ).await?;
+
+    Ok(answer)
 }
 
 #[tauri::command]
 pub async fn get_question_answers(
     state: State<'_, AppState>,
     question_id: i32,
-) -> Result<Vec<AnswerResponse>, String> {
-    let repo = AnswerRepository::new(state.db.clone());
+) -> Result<Vec<AnswerResponse>> {
+    let repo = AnswerRepository::new(state.db.clone(), state.events.clone());
 
-    match repo.get_answers_by_question_id(question_id).await {
-        Ok(responses) => Ok(responses),
-        Err(e) => Err(e.to_string())
-    }
+    repo.get_answers_by_question_id(question_id).await
 }
 
 #[tauri::command]
 pub async fn accept_answer(
     state: State<'_, AppState>,
+    token: String,
     answer_id: i32,
-) -> Result<(), String> {
-    let repo = AnswerRepository::new(state.db.clone());
+) -> Result<()> {
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = AnswerRepository::new(state.db.clone(), state.events.clone());
 
-    match repo.accept_answer(answer_id).await {
-        Ok(_) => Ok(()),
-        Err(e) => Err(e.to_string())
-    }
+    repo.accept_answer(answer_id, user_id).await
 }
\ No newline at end of file
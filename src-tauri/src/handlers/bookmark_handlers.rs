@@ -1,80 +1,82 @@
 use tauri::State;
+use crate::auth::{authorize, Scope};
 use crate::models::bookmark_models::*;
 use crate::repository::bookmark_repository::BookmarkRepository;
+use crate::error::Result;
 use crate::AppState;
+use validator::Validate;
 
 #[tauri::command]
 pub async fn create_bookmark(
     state: State<'_, AppState>,
-    user_id: i32,
+    token: String,
     request: CreateBookmarkRequest,
-) -> Result<BookmarkResponse, String> {
+) -> Result<BookmarkResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
     let repo = BookmarkRepository::new(state.db.clone());
 
-    match repo.create_bookmark(
+    repo.create_bookmark(
         user_id,
         request.target_id,
         request.target_type,
         request.title,
         request.note,
-    ).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+    ).await
 }
 
 #[tauri::command]
 pub async fn list_bookmarks(
     state: State<'_, AppState>,
-    user_id: i32,
-) -> Result<Vec<BookmarkResponse>, String> {
+    token: String,
+) -> Result<Vec<BookmarkResponse>> {
+    let user_id = authorize(&state, &token, Scope::Read).await?;
+
     let repo = BookmarkRepository::new(state.db.clone());
 
-    match repo.list_bookmarks(user_id).await {
-        Ok(responses) => Ok(responses),
-        Err(e) => Err(e.to_string())
-    }
+    repo.list_bookmarks(user_id).await
 }
 
 #[tauri::command]
 pub async fn get_bookmark(
     state: State<'_, AppState>,
     bookmark_id: i32,
-    user_id: i32,
-) -> Result<BookmarkWithContentResponse, String> {
+    token: String,
+) -> Result<BookmarkWithContentResponse> {
+    let user_id = authorize(&state, &token, Scope::Read).await?;
+
     let repo = BookmarkRepository::new(state.db.clone());
 
-    match repo.get_bookmark(bookmark_id, user_id).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+    repo.get_bookmark(bookmark_id, user_id).await
 }
 
 #[tauri::command]
 pub async fn update_bookmark(
     state: State<'_, AppState>,
     bookmark_id: i32,
-    user_id: i32,
+    token: String,
     request: UpdateBookmarkRequest,
-) -> Result<BookmarkResponse, String> {
+) -> Result<BookmarkResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
     let repo = BookmarkRepository::new(state.db.clone());
 
-    match repo.update_bookmark(bookmark_id, user_id, request.title, request.note).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+    repo.update_bookmark(bookmark_id, user_id, request.title, request.note).await
 }
 
 #[tauri::command]
 pub async fn delete_bookmark(
     state: State<'_, AppState>,
     bookmark_id: i32,
-    user_id: i32,
-) -> Result<(), String> {
+    token: String,
+) -> Result<()> {
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
     let repo = BookmarkRepository::new(state.db.clone());
 
-    match repo.delete_bookmark(bookmark_id, user_id).await {
-        Ok(_) => Ok(()),
-        Err(e) => Err(e.to_string())
-    }
+    repo.delete_bookmark(bookmark_id, user_id).await
 }
\ No newline at end of file
@@ -1,74 +1,96 @@
 use tauri::State;
+use crate::auth::{authorize, Scope};
+use crate::error::Result;
+use crate::entities::sea_orm_active_enums::TargetType;
 use crate::models::comment_models::*;
 use crate::repository::comment_repository::CommentRepository;
 use crate::AppState;
+use validator::Validate;
 
 #[tauri::command]
 pub async fn create_comment(
     state: State<'_, AppState>,
-    user_id: i32,
+    token: String,
     request: CreateCommentRequest,
-) -> Result<CommentResponse, String> {
-    if request.content.trim().is_empty() {
-        return Err("Comment content cannot be empty.".into());
-    }
-    
-    let repo = CommentRepository::new(state.db.clone());
+) -> Result<CommentResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = CommentRepository::new(state.db.clone(), state.events.clone());
     
-    match repo.create_comment(
+    repo.create_comment(
         user_id,
         request.target_id,
         request.target_type,
+        request.parent_comment_id,
         request.content,
-    ).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+        state.comments.max_depth,
+    ).await
 }
 
 #[tauri::command]
 pub async fn get_comments(
     state: State<'_, AppState>,
     target_id: i32,
-    target_type: String,
-) -> Result<Vec<CommentResponse>, String> {
-    let repo = CommentRepository::new(state.db.clone());
+    target_type: TargetType,
+) -> Result<Vec<CommentResponse>> {
+    let repo = CommentRepository::new(state.db.clone(), state.events.clone());
     
-    match repo.get_comments(target_id, &target_type).await {
-        Ok(responses) => Ok(responses),
-        Err(e) => Err(e.to_string())
-    }
+    repo.get_comments(target_id, target_type).await
 }
 
 #[tauri::command]
 pub async fn update_comment(
     state: State<'_, AppState>,
     comment_id: i32,
-    user_id: i32,
+    token: String,
     request: UpdateCommentRequest,
-) -> Result<CommentResponse, String> {
-    if request.content.trim().is_empty() {
-        return Err("Comment content cannot be empty.".into());
-    }
-    
-    let repo = CommentRepository::new(state.db.clone());
+) -> Result<CommentResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = CommentRepository::new(state.db.clone(), state.events.clone());
     
-    match repo.update_comment(comment_id, user_id, request.content).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+    repo.update_comment(comment_id, user_id, request.content).await
 }
 
 #[tauri::command]
 pub async fn delete_comment(
     state: State<'_, AppState>,
     comment_id: i32,
-    user_id: i32,
-) -> Result<(), String> {
-    let repo = CommentRepository::new(state.db.clone());
+    token: String,
+) -> Result<()> {
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = CommentRepository::new(state.db.clone(), state.events.clone());
     
-    match repo.delete_comment(comment_id, user_id).await {
-        Ok(_) => Ok(()),
-        Err(e) => Err(e.to_string())
-    }
-} 
\ No newline at end of file
+    repo.delete_comment(comment_id, user_id).await
+}
+
+#[tauri::command]
+pub async fn upvote_comment(
+    state: State<'_, AppState>,
+    comment_id: i32,
+    token: String,
+) -> Result<CommentResponse> {
+    let user_id = authorize(&state, &token, Scope::WriteVotes).await?;
+
+    let repo = CommentRepository::new(state.db.clone(), state.events.clone());
+
+    repo.upvote_comment(comment_id, user_id).await
+}
+
+#[tauri::command]
+pub async fn remove_comment_upvote(
+    state: State<'_, AppState>,
+    comment_id: i32,
+    token: String,
+) -> Result<CommentResponse> {
+    let user_id = authorize(&state, &token, Scope::WriteVotes).await?;
+
+    let repo = CommentRepository::new(state.db.clone(), state.events.clone());
+
+    repo.remove_comment_upvote(comment_id, user_id).await
+}
@@ -0,0 +1,58 @@
+use tauri::State;
+use crate::auth::{authorize, authorize_moderator, Scope};
+use crate::models::flag_models::*;
+use crate::repository::flag_repository::FlagRepository;
+use crate::error::Result;
+use crate::AppState;
+use validator::Validate;
+
+#[tauri::command]
+pub async fn flag_post(
+    state: State<'_, AppState>,
+    token: String,
+    request: CreateFlagRequest,
+) -> Result<FlagResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = FlagRepository::new(state.db.clone());
+
+    repo.create_flag(
+        user_id,
+        request.post_type,
+        request.post_id,
+        request.reason,
+        request.text,
+        state.comments.flag_hide_threshold,
+    ).await
+}
+
+#[tauri::command]
+pub async fn get_flag_queue(
+    state: State<'_, AppState>,
+    token: String,
+    query: FlagQueueQuery,
+) -> Result<Vec<FlaggedPostResponse>> {
+    authorize_moderator(&state, &token).await?;
+
+    let repo = FlagRepository::new(state.db.clone());
+
+    repo.get_queue(query.page.unwrap_or(1), query.per_page.unwrap_or(20)).await
+}
+
+#[tauri::command]
+pub async fn resolve_flag(
+    state: State<'_, AppState>,
+    token: String,
+    flag_id: i32,
+    request: ResolveFlagRequest,
+) -> Result<FlagResponse> {
+    request.validate()?;
+
+    let moderator_id = authorize_moderator(&state, &token).await?;
+
+    let repo = FlagRepository::new(state.db.clone());
+
+    repo.resolve_flag(moderator_id, flag_id, request).await
+}
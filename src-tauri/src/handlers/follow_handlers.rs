@@ -0,0 +1,95 @@
+use tauri::State;
+use crate::auth::{authorize, authorize_session, Scope};
+use crate::models::follow_models::*;
+use crate::repository::follow_repository::FollowRepository;
+use crate::error::Result;
+use crate::AppState;
+
+#[tauri::command]
+pub async fn follow_question(
+    state: State<'_, AppState>,
+    token: String,
+    question_id: i32,
+) -> Result<Vec<FollowedQuestionResponse>> {
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = FollowRepository::new(state.db.clone());
+
+    repo.follow_question(user_id, question_id).await
+}
+
+#[tauri::command]
+pub async fn unfollow_question(
+    state: State<'_, AppState>,
+    token: String,
+    question_id: i32,
+) -> Result<Vec<FollowedQuestionResponse>> {
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = FollowRepository::new(state.db.clone());
+
+    repo.unfollow_question(user_id, question_id).await
+}
+
+#[tauri::command]
+pub async fn list_followed_questions(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<Vec<FollowedQuestionResponse>> {
+    let user_id = authorize(&state, &token, Scope::Read).await?;
+
+    let repo = FollowRepository::new(state.db.clone());
+
+    repo.list_followed_questions(user_id).await
+}
+
+#[tauri::command]
+pub async fn get_tag_preferences(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<TagPreferencesResponse> {
+    let user_id = authorize(&state, &token, Scope::Read).await?;
+
+    let repo = FollowRepository::new(state.db.clone());
+
+    repo.get_tag_preferences(user_id).await
+}
+
+#[tauri::command]
+pub async fn set_tag_preference(
+    state: State<'_, AppState>,
+    token: String,
+    request: SetTagPreferenceRequest,
+) -> Result<TagPreferencesResponse> {
+    let user_id = authorize_session(&state, &token)?;
+
+    let repo = FollowRepository::new(state.db.clone());
+
+    repo.set_tag_preference(user_id, request.tag_id, request.preference).await
+}
+
+#[tauri::command]
+pub async fn clear_tag_preference(
+    state: State<'_, AppState>,
+    token: String,
+    tag_id: i32,
+) -> Result<TagPreferencesResponse> {
+    let user_id = authorize_session(&state, &token)?;
+
+    let repo = FollowRepository::new(state.db.clone());
+
+    repo.clear_tag_preference(user_id, tag_id).await
+}
+
+#[tauri::command]
+pub async fn update_ignored_tag_display(
+    state: State<'_, AppState>,
+    token: String,
+    request: UpdateIgnoredTagDisplayRequest,
+) -> Result<TagPreferencesResponse> {
+    let user_id = authorize_session(&state, &token)?;
+
+    let repo = FollowRepository::new(state.db.clone());
+
+    repo.set_hide_ignored_tags(user_id, request.hide_ignored_tags).await
+}
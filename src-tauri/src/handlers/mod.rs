@@ -4,4 +4,13 @@ pub mod answer_handlers;
 pub mod vote_handlers;
 pub mod tag_handlers;
 pub mod comment_handlers;
-pub mod bookmark_handlers;
\ No newline at end of file
+pub mod bookmark_handlers;
+pub mod two_factor_handlers;
+pub mod personal_access_token_handlers;
+pub mod flag_handlers;
+pub mod review_handlers;
+pub mod notification_handlers;
+pub mod activity_handlers;
+pub mod follow_handlers;
+pub mod tag_synonym_handlers;
+pub mod render_handlers;
\ No newline at end of file
@@ -0,0 +1,79 @@
+use tauri::State;
+use crate::auth::{authorize, authorize_session, Scope};
+use crate::models::notification_models::*;
+use crate::repository::notification_repository::NotificationRepository;
+use crate::error::Result;
+use crate::AppState;
+use validator::Validate;
+
+#[tauri::command]
+pub async fn list_notifications(
+    state: State<'_, AppState>,
+    token: String,
+    query: NotificationQuery,
+) -> Result<Vec<NotificationResponse>> {
+    let user_id = authorize(&state, &token, Scope::Read).await?;
+
+    let repo = NotificationRepository::new(state.db.clone());
+
+    repo.list_notifications(
+        user_id,
+        query.page.unwrap_or(1),
+        query.per_page.unwrap_or(20),
+        query.unread_only.unwrap_or(false),
+    ).await
+}
+
+#[tauri::command]
+pub async fn get_unread_notification_count(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<UnreadCountResponse> {
+    let user_id = authorize(&state, &token, Scope::Read).await?;
+
+    let repo = NotificationRepository::new(state.db.clone());
+
+    repo.get_unread_count(user_id).await
+}
+
+#[tauri::command]
+pub async fn mark_notifications_read(
+    state: State<'_, AppState>,
+    token: String,
+    request: MarkNotificationsReadRequest,
+) -> Result<UnreadCountResponse> {
+    request.validate()?;
+
+    let user_id = authorize_session(&state, &token)?;
+
+    let repo = NotificationRepository::new(state.db.clone());
+
+    repo.mark_read(user_id, request.ids).await
+}
+
+#[tauri::command]
+pub async fn get_notification_preferences(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<Vec<NotificationPreference>> {
+    let user_id = authorize_session(&state, &token)?;
+
+    let repo = NotificationRepository::new(state.db.clone());
+
+    repo.get_preferences(user_id).await
+}
+
+#[tauri::command]
+pub async fn update_notification_preferences(
+    state: State<'_, AppState>,
+    token: String,
+    request: UpdateNotificationPreferencesRequest,
+) -> Result<Vec<NotificationPreference>> {
+    request.validate()?;
+
+    let user_id = authorize_session(&state, &token)?;
+
+    let repo = NotificationRepository::new(state.db.clone());
+
+    repo.update_preferences(user_id, request.preferences).await
+}
@@ -0,0 +1,52 @@
+use tauri::State;
+use crate::auth::authorize_session;
+use crate::models::personal_access_token_models::*;
+use crate::repository::personal_access_token_repository::PersonalAccessTokenRepository;
+use crate::error::Result;
+use crate::AppState;
+use validator::Validate;
+
+#[tauri::command]
+pub async fn create_personal_access_token(
+    state: State<'_, AppState>,
+    token: String,
+    request: CreatePersonalAccessTokenRequest,
+) -> Result<CreatedPersonalAccessTokenResponse> {
+    request.validate()?;
+
+    let user_id = authorize_session(&state, &token)?;
+
+    let repo = PersonalAccessTokenRepository::new(state.db.clone());
+
+    repo.create_token(
+        user_id,
+        request.name,
+        request.scopes,
+        request.expires_in_days,
+    ).await
+}
+
+#[tauri::command]
+pub async fn list_personal_access_tokens(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<Vec<PersonalAccessTokenResponse>> {
+    let user_id = authorize_session(&state, &token)?;
+
+    let repo = PersonalAccessTokenRepository::new(state.db.clone());
+
+    repo.list_tokens(user_id).await
+}
+
+#[tauri::command]
+pub async fn revoke_personal_access_token(
+    state: State<'_, AppState>,
+    token: String,
+    token_id: i32,
+) -> Result<()> {
+    let user_id = authorize_session(&state, &token)?;
+
+    let repo = PersonalAccessTokenRepository::new(state.db.clone());
+
+    repo.revoke_token(token_id, user_id).await
+}
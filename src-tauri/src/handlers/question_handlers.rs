@@ -1,67 +1,136 @@
 use tauri::State;
-use serde::Deserialize;
+use crate::auth::{authorize, authorize_moderator, Scope};
+use crate::error::Result;
 use crate::models::question_models::*;
 use crate::repository::question_repository::QuestionRepository;
+use crate::repository::search_repository::SearchRepository;
 use crate::AppState;
+use validator::Validate;
 
 #[tauri::command]
 pub async fn create_question(
     state: State<'_, AppState>,
-    user_id: i32,
+    token: String,
     request: CreateQuestionRequest,
-) -> Result<QuestionResponse, String> {
-    if request.title.trim().is_empty() {
-        return Err("Title cannot be empty".to_string());
-    }
-    
-    let repo = QuestionRepository::new(state.db.clone());
+) -> Result<QuestionResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = QuestionRepository::new(state.db.clone(), state.events.clone());
     
-    match repo.create_question(
+    let question = repo.create_question(
         user_id,
         request.title,
         request.content,
         request.tags,
-    ).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+        &state.tags,
+        &state.reviews,
+    ).await?;
+
+    Ok(question)
+}
+
+#[tauri::command]
+pub async fn suggest_similar_questions(
+    state: State<'_, AppState>,
+    request: SimilarQuestionsRequest,
+) -> Result<Vec<SimilarQuestion>> {
+    request.validate()?;
+
+    let repo = SearchRepository::new(state.db.clone());
+
+    repo.suggest_similar(
+        &request.title,
+        request.content.as_deref().unwrap_or_default(),
+        request.tags.unwrap_or_default(),
+    ).await
 }
 
 #[tauri::command]
 pub async fn get_question(
     state: State<'_, AppState>,
     id: i32,
-) -> Result<QuestionResponse, String> {
-    let repo = QuestionRepository::new(state.db.clone());
+) -> Result<QuestionResponse> {
+    let repo = QuestionRepository::new(state.db.clone(), state.events.clone());
     
-    match repo.get_question_by_id(id).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+    repo.get_question_by_id(id).await
+}
+
+#[tauri::command]
+pub async fn get_question_sidebar(
+    state: State<'_, AppState>,
+    id: i32,
+) -> Result<QuestionSidebarResponse> {
+    let repo = QuestionRepository::new(state.db.clone(), state.events.clone());
+
+    Ok(QuestionSidebarResponse {
+        related: repo.get_related_questions(id).await?,
+        linked: repo.get_linked_questions(id).await?,
+    })
+}
+
+#[tauri::command]
+pub async fn update_question(
+    state: State<'_, AppState>,
+    token: String,
+    id: i32,
+    request: UpdateQuestionRequest,
+) -> Result<QuestionResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = QuestionRepository::new(state.db.clone(), state.events.clone());
+
+    repo.update_question(
+        id,
+        user_id,
+        request.title,
+        request.content,
+        request.tags,
+        &state.tags,
+    ).await
 }
 
 #[tauri::command]
 pub async fn list_questions(
     state: State<'_, AppState>,
+    token: Option<String>,
     query: QuestionQuery,
-) -> Result<Vec<QuestionResponse>, String> {
-    let repo = QuestionRepository::new(state.db.clone());
+) -> Result<Vec<QuestionResponse>> {
+    let viewer_id = match token {
+        Some(token) => Some(authorize(&state, &token, Scope::Read).await?),
+        None => None,
+    };
+
+    let repo = QuestionRepository::new(state.db.clone(), state.events.clone());
     
-    match repo.list_questions(
-        query.page.unwrap_or(1),
-        query.per_page.unwrap_or(10),
-        query.sort_by,
-        query.tag,
-        query.search,
-    ).await {
-        Ok(responses) => Ok(responses),
-        Err(e) => Err(e.to_string())
-    }
+    repo.list_questions(query, viewer_id).await
+}
+
+#[tauri::command]
+pub async fn close_question(
+    state: State<'_, AppState>,
+    token: String,
+    id: i32,
+) -> Result<QuestionResponse> {
+    authorize_moderator(&state, &token).await?;
+
+    let repo = QuestionRepository::new(state.db.clone(), state.events.clone());
+
+    repo.set_closed(id, true).await
 }
 
-#[derive(Deserialize)]
-pub struct CreateQuestionRequest {
-    pub title: String,
-    pub content: String,
-    pub tags: Vec<String>,
+#[tauri::command]
+pub async fn reopen_question(
+    state: State<'_, AppState>,
+    token: String,
+    id: i32,
+) -> Result<QuestionResponse> {
+    authorize_moderator(&state, &token).await?;
+
+    let repo = QuestionRepository::new(state.db.clone(), state.events.clone());
+
+    repo.set_closed(id, false).await
 }
@@ -0,0 +1,7 @@
+use crate::error::Result;
+use crate::markdown;
+
+#[tauri::command]
+pub async fn get_code_theme_css() -> Result<String> {
+    Ok(markdown::theme_css().to_string())
+}
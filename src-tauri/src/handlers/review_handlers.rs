@@ -0,0 +1,54 @@
+use tauri::State;
+use crate::auth::{authorize, Scope};
+use crate::models::review_models::*;
+use crate::repository::review_repository::ReviewRepository;
+use crate::error::Result;
+use crate::AppState;
+use validator::Validate;
+
+#[tauri::command]
+pub async fn get_review_summaries(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<Vec<ReviewQueueSummary>> {
+    authorize(&state, &token, Scope::Read).await?;
+
+    let repo = ReviewRepository::new(state.db.clone());
+
+    repo.get_summaries().await
+}
+
+#[tauri::command]
+pub async fn get_review_queue(
+    state: State<'_, AppState>,
+    token: String,
+    query: ReviewQueueQuery,
+) -> Result<Vec<ReviewTaskResponse>> {
+    let user_id = authorize(&state, &token, Scope::Read).await?;
+
+    let repo = ReviewRepository::new(state.db.clone());
+
+    repo.get_queue(
+        user_id,
+        query.queue,
+        query.page.unwrap_or(1),
+        query.per_page.unwrap_or(10),
+        &state.reviews,
+    ).await
+}
+
+#[tauri::command]
+pub async fn submit_review(
+    state: State<'_, AppState>,
+    token: String,
+    task_id: i32,
+    request: SubmitReviewRequest,
+) -> Result<ReviewTaskResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = ReviewRepository::new(state.db.clone());
+
+    repo.submit_review(user_id, task_id, request, &state.reviews).await
+}
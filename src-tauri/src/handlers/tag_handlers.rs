@@ -1,55 +1,153 @@
 use tauri::State;
+use crate::auth::{authorize, authorize_moderator, Scope};
+use crate::error::Result;
 use crate::models::tag_models::*;
 use crate::repository::tag_repository::TagRepository;
 use crate::AppState;
+use validator::Validate;
 
 #[tauri::command]
 pub async fn create_tag(
     state: State<'_, AppState>,
+    token: String,
     request: CreateTagRequest,
-) -> Result<TagResponse, String> {
+) -> Result<TagResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
     let repo = TagRepository::new(state.db.clone());
     
-    match repo.create_tag(request.name, request.description).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+    repo.create_tag(user_id, request.name, request.excerpt, request.wiki, &state.tags).await
 }
 
 #[tauri::command]
 pub async fn get_tag(
     state: State<'_, AppState>,
     id: i32,
-) -> Result<TagResponse, String> {
+) -> Result<TagResponse> {
     let repo = TagRepository::new(state.db.clone());
     
-    match repo.get_tag_by_id(id).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+    repo.get_tag_by_id(id).await
+}
+
+#[tauri::command]
+pub async fn get_tag_wiki(
+    state: State<'_, AppState>,
+    tag_id: i32,
+) -> Result<TagWikiResponse> {
+    let repo = TagRepository::new(state.db.clone());
+
+    repo.get_wiki(tag_id).await
+}
+
+#[tauri::command]
+pub async fn get_tag_revisions(
+    state: State<'_, AppState>,
+    tag_id: i32,
+) -> Result<Vec<TagRevisionResponse>> {
+    let repo = TagRepository::new(state.db.clone());
+
+    repo.get_revisions(tag_id).await
+}
+
+#[tauri::command]
+pub async fn edit_tag_wiki(
+    state: State<'_, AppState>,
+    token: String,
+    tag_id: i32,
+    request: EditTagWikiRequest,
+) -> Result<TagWikiResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = TagRepository::new(state.db.clone());
+
+    repo.edit_wiki(user_id, tag_id, request, &state.tags).await
 }
 
 #[tauri::command]
 pub async fn list_tags(
     state: State<'_, AppState>,
-) -> Result<Vec<TagResponse>, String> {
+) -> Result<Vec<TagResponse>> {
     let repo = TagRepository::new(state.db.clone());
     
-    match repo.list_tags().await {
-        Ok(responses) => Ok(responses),
-        Err(e) => Err(e.to_string())
-    }
+    repo.list_tags().await
 }
 
 #[tauri::command]
 pub async fn search_tags(
     state: State<'_, AppState>,
     query: String,
-) -> Result<Vec<TagResponse>, String> {
+) -> Result<Vec<TagResponse>> {
     let repo = TagRepository::new(state.db.clone());
     
-    match repo.search_tags(&query).await {
-        Ok(responses) => Ok(responses),
-        Err(e) => Err(e.to_string())
-    }
+    repo.search_tags(&query).await
+}
+
+#[tauri::command]
+pub async fn update_tag(
+    state: State<'_, AppState>,
+    token: String,
+    id: i32,
+    request: UpdateTagRequest,
+) -> Result<TagResponse> {
+    request.validate()?;
+
+    let moderator_id = authorize_moderator(&state, &token).await?;
+
+    let repo = TagRepository::new(state.db.clone());
+
+    repo.update_tag(moderator_id, id, request).await
+}
+
+#[tauri::command]
+pub async fn merge_tags(
+    state: State<'_, AppState>,
+    token: String,
+    source_id: i32,
+    target_id: i32,
+) -> Result<TagResponse> {
+    let moderator_id = authorize_moderator(&state, &token).await?;
+
+    let repo = TagRepository::new(state.db.clone());
+
+    repo.merge_tags(moderator_id, source_id, target_id).await
+}
+
+#[tauri::command]
+pub async fn delete_tag(
+    state: State<'_, AppState>,
+    token: String,
+    id: i32,
+) -> Result<()> {
+    let moderator_id = authorize_moderator(&state, &token).await?;
+
+    let repo = TagRepository::new(state.db.clone());
+
+    repo.delete_tag(moderator_id, id).await
+}
+
+#[tauri::command]
+pub async fn get_tag_audit_log(
+    state: State<'_, AppState>,
+    token: String,
+    query: TagAuditLogQuery,
+) -> Result<Vec<TagAuditLogResponse>> {
+    authorize_moderator(&state, &token).await?;
+
+    let repo = TagRepository::new(state.db.clone());
+
+    repo.get_audit_log(query.tag_id, query.page.unwrap_or(1), query.per_page.unwrap_or(20)).await
+}
+
+#[tauri::command]
+pub async fn autocomplete_tags(
+    state: State<'_, AppState>,
+    query: TagAutocompleteQuery,
+) -> Result<Vec<TagSuggestion>> {
+    let repo = TagRepository::new(state.db.clone());
+
+    repo.autocomplete(&query.term, query.limit, query.exclude.unwrap_or_default()).await
 }
@@ -0,0 +1,67 @@
+use tauri::State;
+use crate::auth::{authorize, authorize_moderator, Scope};
+use crate::entities::sea_orm_active_enums::VoteType;
+use crate::models::tag_synonym_models::*;
+use crate::repository::tag_synonym_repository::TagSynonymRepository;
+use crate::error::Result;
+use crate::AppState;
+use validator::Validate;
+
+#[tauri::command]
+pub async fn suggest_tag_synonym(
+    state: State<'_, AppState>,
+    token: String,
+    request: SuggestTagSynonymRequest,
+) -> Result<TagSynonymResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteQuestions).await?;
+
+    let repo = TagSynonymRepository::new(state.db.clone());
+
+    repo.suggest(user_id, request.source_name, request.target_tag_id, &state.tags).await
+}
+
+#[tauri::command]
+pub async fn vote_tag_synonym(
+    state: State<'_, AppState>,
+    token: String,
+    synonym_id: i32,
+    vote_type: VoteType,
+) -> Result<TagSynonymResponse> {
+    let user_id = authorize(&state, &token, Scope::WriteVotes).await?;
+
+    let repo = TagSynonymRepository::new(state.db.clone());
+
+    repo.vote(user_id, synonym_id, vote_type, &state.tags).await
+}
+
+#[tauri::command]
+pub async fn review_tag_synonym(
+    state: State<'_, AppState>,
+    token: String,
+    synonym_id: i32,
+    approve: bool,
+) -> Result<TagSynonymResponse> {
+    let moderator_id = authorize_moderator(&state, &token).await?;
+
+    let repo = TagSynonymRepository::new(state.db.clone());
+
+    repo.review(moderator_id, synonym_id, approve).await
+}
+
+#[tauri::command]
+pub async fn list_tag_synonyms(
+    state: State<'_, AppState>,
+    token: Option<String>,
+    query: TagSynonymQuery,
+) -> Result<Vec<TagSynonymResponse>> {
+    let viewer_id = match token {
+        Some(token) => Some(authorize(&state, &token, Scope::Read).await?),
+        None => None,
+    };
+
+    let repo = TagSynonymRepository::new(state.db.clone());
+
+    repo.list_synonyms(query.tag_id, query.status, viewer_id).await
+}
@@ -0,0 +1,44 @@
+use tauri::{command, State};
+
+use crate::auth::authorize_session;
+use crate::error::Result;
+use crate::models::two_factor_models::*;
+use crate::repository::two_factor_repository::TwoFactorRepository;
+use crate::AppState;
+use validator::Validate;
+
+#[command]
+pub async fn begin_totp_enrollment(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<TotpEnrollmentResponse> {
+    let user_id = authorize_session(&state, &token)?;
+
+    TwoFactorRepository::begin_enrollment(&state.db, user_id).await
+}
+
+#[command]
+pub async fn confirm_totp_enrollment(
+    state: State<'_, AppState>,
+    token: String,
+    request: ConfirmTotpRequest,
+) -> Result<RecoveryCodesResponse> {
+    request.validate()?;
+
+    let user_id = authorize_session(&state, &token)?;
+
+    TwoFactorRepository::confirm_enrollment(&state.db, user_id, &request.code).await
+}
+
+#[command]
+pub async fn disable_totp(
+    state: State<'_, AppState>,
+    token: String,
+    request: DisableTotpRequest,
+) -> Result<()> {
+    request.validate()?;
+
+    let user_id = authorize_session(&state, &token)?;
+
+    TwoFactorRepository::disable(&state.db, &state.passwords, user_id, &request.password, &request.code).await
+}
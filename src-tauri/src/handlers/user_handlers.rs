@@ -1,38 +1,36 @@
-use chrono::{Duration, Utc};
-use jsonwebtoken::{encode, EncodingKey, Header};
-use serde::{Deserialize, Serialize};
-use std::env;
 use tauri::{command, State};
 
 use crate::AppState;
+use validator::Validate;
+use crate::error::{AppError, Result};
+use crate::auth::keys::KeyManager;
+use crate::auth::{authorize, authorize_session, session, Scope};
 use crate::models::user_models::{
-    AuthResponse, ErrorResponse, LoginRequest, RegisterRequest, UserResponse,
-    UpdateProfileRequest, ChangePasswordRequest, UserProfileResponse, Badge,
+    AuthResponse, LoginRequest, LoginResponse, RegisterRequest, UserResponse,
+    UpdateProfileRequest, ChangePasswordRequest, UserProfileResponse, Badge, VerifyEmailRequest,
 };
+use crate::models::question_models::QuestionQuery;
+use crate::models::two_factor_models::TwoFactorLoginRequest;
 use crate::repository::user_repository::UserRepository;
 use crate::repository::question_repository::QuestionRepository;
-
-#[derive(Debug, Serialize, Deserialize)]
-struct Claims {
-    sub: String,
-    exp: usize,
-    iat: usize,
-}
+use crate::repository::two_factor_repository::TwoFactorRepository;
 
 #[command]
 pub async fn register(
     state: State<'_, AppState>,
     request: RegisterRequest,
-) -> Result<AuthResponse, String> {
-    match UserRepository::register(&state.db, request).await {
+) -> Result<AuthResponse> {
+    request.validate()?;
+
+    match UserRepository::register(&state.db, state.mailer.as_ref(), &state.passwords, request).await {
         Ok(user) => {
             let user_response = UserRepository::model_to_response(user);
-            match generate_token(user_response.id) {
+            match generate_token(&state.keys, user_response.id) {
                 Ok(token) => Ok(AuthResponse {
                     user: user_response,
                     token,
                 }),
-                Err(e) => Err(e.message)
+                Err(e) => Err(e)
             }
         }
         Err(e) => Err(e),
@@ -43,34 +41,98 @@ pub async fn register(
 pub async fn login(
     state: State<'_, AppState>,
     request: LoginRequest,
-) -> Result<AuthResponse, String> {
-    match UserRepository::login(&state.db, request).await {
+) -> Result<LoginResponse> {
+    request.validate()?;
+
+    match UserRepository::login(&state.db, &state.passwords, request).await {
+        Ok(user) if user.totp_enabled_at.is_some() => {
+            match generate_challenge_token(&state.keys, user.id) {
+                Ok(challenge_token) => Ok(LoginResponse::TwoFactorRequired { challenge_token }),
+                Err(e) => Err(e)
+            }
+        }
         Ok(user) => {
             let user_response = UserRepository::model_to_response(user);
-            match generate_token(user_response.id) {
-                Ok(token) => Ok(AuthResponse {
+            match generate_token(&state.keys, user_response.id) {
+                Ok(token) => Ok(LoginResponse::Authenticated(AuthResponse {
                     user: user_response,
                     token,
-                }),
-                Err(e) => Err(e.message)
+                })),
+                Err(e) => Err(e)
             }
         }
         Err(e) => Err(e),
     }
 }
 
+#[command]
+pub async fn complete_two_factor_login(
+    state: State<'_, AppState>,
+    request: TwoFactorLoginRequest,
+) -> Result<AuthResponse> {
+    request.validate()?;
+
+    let user_id = session::verify_challenge_token(&state.keys, &request.challenge_token)?;
+
+    let user = match UserRepository::find_by_id(&state.db, user_id).await? {
+        Some(user) => user,
+        None => return Err(AppError::NotFound("User not found".to_string())),
+    };
+
+    TwoFactorRepository::verify_second_factor(&state.db, &user, &request.code).await?;
+
+    let user = UserRepository::record_login(&state.db, user).await?;
+    let user_response = UserRepository::model_to_response(user);
+    match generate_token(&state.keys, user_response.id) {
+        Ok(token) => Ok(AuthResponse {
+            user: user_response,
+            token,
+        }),
+        Err(e) => Err(e)
+    }
+}
+
+#[command]
+pub async fn verify_email(
+    state: State<'_, AppState>,
+    request: VerifyEmailRequest,
+) -> Result<UserResponse> {
+    request.validate()?;
+
+    match UserRepository::verify_email(&state.db, &request.token).await {
+        Ok(user) => Ok(UserRepository::model_to_response(user)),
+        Err(e) => Err(e),
+    }
+}
+
+#[command]
+pub async fn resend_verification_email(
+    state: State<'_, AppState>,
+    token: String,
+) -> Result<()> {
+    let user_id = authorize_session(&state, &token)?;
+
+    match UserRepository::find_by_id(&state.db, user_id).await? {
+        Some(user) => {
+            UserRepository::send_verification_email(&state.db, state.mailer.as_ref(), &user).await
+        }
+        None => Err(AppError::NotFound("User not found".to_string())),
+    }
+}
+
 #[command]
 pub async fn get_current_user(
     state: State<'_, AppState>,
-    user_id: i32,
-) -> Result<UserResponse, String> {
-    match UserRepository::find_by_id(&state.db, user_id).await {
-        Ok(Some(user)) => {
+    token: String,
+) -> Result<UserResponse> {
+    let user_id = authorize(&state, &token, Scope::Read).await?;
+
+    match UserRepository::find_by_id(&state.db, user_id).await? {
+        Some(user) => {
             let user_response = UserRepository::model_to_response(user);
             Ok(user_response)
         }
-        Ok(None) => Err("User not found".to_string()),
-        Err(e) => Err(format!("Database error: {}", e)),
+        None => Err(AppError::NotFound("User not found".to_string())),
     }
 }
 
@@ -78,13 +140,13 @@ pub async fn get_current_user(
 pub async fn get_user_profile(
     state: State<'_, AppState>,
     profile_user_id: i32,
-) -> Result<UserProfileResponse, String> {
-    match UserRepository::find_by_id(&state.db, profile_user_id).await {
-        Ok(Some(user)) => {
+) -> Result<UserProfileResponse> {
+    match UserRepository::find_by_id(&state.db, profile_user_id).await? {
+        Some(user) => {
             let user_response = UserRepository::model_to_response(user);
             
-            let question_repo = QuestionRepository::new(state.db.clone());
-            match question_repo.list_questions(1, 1000, None, None, None).await {
+            let question_repo = QuestionRepository::new(state.db.clone(), state.events.clone());
+            match question_repo.list_questions(QuestionQuery { per_page: Some(1000), ..Default::default() }, None).await {
                 Ok(questions) => {
                     let filtered_questions = questions
                         .into_iter()
@@ -102,20 +164,23 @@ pub async fn get_user_profile(
                         badges,
                     })
                 },
-                Err(e) => Err(format!("Error retrieving questions: {}", e))
+                Err(e) => Err(e)
             }
         },
-        Ok(None) => Err("User not found".to_string()),
-        Err(e) => Err(format!("Database error: {}", e)),
+        None => Err(AppError::NotFound("User not found".to_string())),
     }
 }
 
 #[command]
 pub async fn update_profile(
     state: State<'_, AppState>,
-    user_id: i32,
+    token: String,
     request: UpdateProfileRequest,
-) -> Result<UserResponse, String> {
+) -> Result<UserResponse> {
+    request.validate()?;
+
+    let user_id = authorize_session(&state, &token)?;
+
     match UserRepository::update_profile(&state.db, user_id, request).await {
         Ok(user) => {
             let user_response = UserRepository::model_to_response(user);
@@ -128,34 +193,25 @@ pub async fn update_profile(
 #[command]
 pub async fn change_password(
     state: State<'_, AppState>,
-    user_id: i32,
+    token: String,
     request: ChangePasswordRequest,
-) -> Result<(), String> {
-    match UserRepository::change_password(&state.db, user_id, request).await {
+) -> Result<()> {
+    request.validate()?;
+
+    let user_id = authorize_session(&state, &token)?;
+
+    match UserRepository::change_password(&state.db, &state.passwords, user_id, request).await {
         Ok(_) => Ok(()),
         Err(e) => Err(e),
     }
 }
 
-fn generate_token(user_id: i32) -> Result<String, ErrorResponse> {
-    let jwt_secret = env::var("JWT_SECRET").unwrap_or_else(|_| "your-256-bit-secret".to_string());
-
-    let now = Utc::now();
-    let iat = now.timestamp() as usize;
-    let exp = (now + Duration::days(7)).timestamp() as usize;
-
-    let claims = Claims {
-        sub: user_id.to_string(),
-        exp,
-        iat,
-    };
+fn generate_token(keys: &KeyManager, user_id: i32) -> Result<String> {
+    session::issue_session_token(keys, user_id)
+        .map_err(|e| AppError::Internal(format!("Failed to generate token: {}", e)))
+}
 
-    encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(jwt_secret.as_bytes()),
-    )
-    .map_err(|e| ErrorResponse {
-        message: format!("Failed to generate token: {}", e),
-    })
+fn generate_challenge_token(keys: &KeyManager, user_id: i32) -> Result<String> {
+    session::issue_challenge_token(keys, user_id)
+        .map_err(|e| AppError::Internal(format!("Failed to generate challenge token: {}", e)))
 }
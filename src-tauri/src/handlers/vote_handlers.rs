@@ -1,37 +1,39 @@
 use tauri::State;
+use crate::auth::{authorize, Scope};
+use crate::entities::sea_orm_active_enums::TargetType;
 use crate::models::vote_models::*;
 use crate::repository::vote_repository::VoteRepository;
+use crate::error::Result;
 use crate::AppState;
+use validator::Validate;
 
 #[tauri::command]
 pub async fn create_vote(
     state: State<'_, AppState>,
-    user_id: i32,
+    token: String,
     request: CreateVoteRequest,
-) -> Result<VoteResponse, String> {
-    let repo = VoteRepository::new(state.db.clone());
+) -> Result<VoteResponse> {
+    request.validate()?;
+
+    let user_id = authorize(&state, &token, Scope::WriteVotes).await?;
+
+    let repo = VoteRepository::new(state.db.clone(), state.events.clone());
     
-    match repo.create_vote(
+    repo.create_vote(
         user_id,
         request.target_id,
         request.target_type,
         request.vote_type,
-    ).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(e.to_string())
-    }
+    ).await
 }
 
 #[tauri::command]
 pub async fn get_vote_count(
     state: State<'_, AppState>,
     target_id: i32,
-    target_type: String,
-) -> Result<VoteCount, String> {
-    let repo = VoteRepository::new(state.db.clone());
+    target_type: TargetType,
+) -> Result<VoteCount> {
+    let repo = VoteRepository::new(state.db.clone(), state.events.clone());
     
-    match repo.get_vote_count(target_id, &target_type).await {
-        Ok(count) => Ok(count),
-        Err(e) => Err(e.to_string())
-    }
+    repo.get_vote_count(target_id, target_type).await
 }
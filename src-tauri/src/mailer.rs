@@ -0,0 +1,33 @@
+use std::env;
+
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+pub trait Mailer: Send + Sync {
+    fn send(&self, message: EmailMessage) -> Result<(), String>;
+}
+
+pub struct LogMailer {
+    from: String,
+}
+
+impl LogMailer {
+    pub fn from_env() -> Self {
+        let from = env::var("MAIL_FROM").unwrap_or_else(|_| "no-reply@queueoverflow.local".to_string());
+        Self { from }
+    }
+}
+
+impl Mailer for LogMailer {
+    fn send(&self, message: EmailMessage) -> Result<(), String> {
+        println!(
+            "📧 Mail from {} to {}\nSubject: {}\n\n{}",
+            self.from, message.to, message.subject, message.body
+        );
+        Ok(())
+    }
+}
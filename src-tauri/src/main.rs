@@ -3,12 +3,18 @@
 use dotenv::dotenv;
 use sea_orm::{Database, DatabaseConnection};
 use sea_orm_migration::prelude::*;
-use std::{env, time::Duration};
+use std::{env, sync::Arc, time::Duration};
 
 mod models;
 mod repository;
 mod handlers;
 mod error;
+mod auth;
+mod mailer;
+mod config;
+mod events;
+mod markdown;
+mod text;
 
 #[path = "../entities/mod.rs"]
 pub mod entities;
@@ -21,11 +27,27 @@ use handlers::{
     vote_handlers::*,
     comment_handlers::*,
     bookmark_handlers::*,
+    two_factor_handlers::*,
+    personal_access_token_handlers::*,
+    flag_handlers::*,
+    review_handlers::*,
+    notification_handlers::*,
+    activity_handlers::*,
+    follow_handlers::*,
+    tag_synonym_handlers::*,
+    render_handlers::*,
 };
 
 #[derive(Clone)]
 pub struct AppState {
     pub db: DatabaseConnection,
+    pub mailer: Arc<dyn mailer::Mailer>,
+    pub passwords: auth::password::PasswordConfig,
+    pub keys: Arc<auth::keys::KeyManager>,
+    pub comments: config::CommentConfig,
+    pub reviews: config::ReviewConfig,
+    pub tags: config::TagConfig,
+    pub events: events::EventBus,
 }
 
 fn main() {
@@ -79,12 +101,11 @@ fn main() {
         }
 
         let db = db.expect("Failed to establish database connection");
-            
-        if env::var("JWT_SECRET").is_err() {
-            let default_secret = "kolin123".to_string();
-            env::set_var("JWT_SECRET", &default_secret);
-            println!("JWT_SECRET not set in environment. Using default value.");
-        }
+
+        let keys = match auth::keys::KeyManager::from_env() {
+            Ok(keys) => keys,
+            Err(e) => panic!("Failed to load JWT signing keys: {}", e),
+        };
 
         println!("Running database migrations...");
         match migration::Migrator::up(&db, None).await {
@@ -101,21 +122,71 @@ fn main() {
             }
         }
 
-        let state = AppState { db };
+        match repository::search_repository::SearchRepository::new(db.clone()).index_missing().await {
+            Ok(0) => {},
+            Ok(count) => println!("✅ Indexed {} questions for similar question search", count),
+            Err(e) => println!("❌ Failed to build similar question index: {}", e),
+        }
+
+        match repository::render_repository::RenderRepository::new(db.clone()).render_missing().await {
+            Ok(0) => {},
+            Ok(count) => println!("✅ Rendered {} posts to HTML", count),
+            Err(e) => println!("❌ Failed to render posts: {}", e),
+        }
+
+        let state = AppState {
+            db,
+            mailer: Arc::new(mailer::LogMailer::from_env()),
+            passwords: auth::password::PasswordConfig::from_env(),
+            keys: Arc::new(keys),
+            comments: config::CommentConfig::from_env(),
+            reviews: config::ReviewConfig::from_env(),
+            tags: config::TagConfig::from_env(),
+            events: events::EventBus::new(),
+        };
+
+        let events = state.events.clone();
+        let window_events = state.events.clone();
 
         tauri::Builder::default()
             .manage(state)
+            .setup(move |app| {
+                events.forward_to_windows(app.handle().clone());
+                Ok(())
+            })
+            .on_window_event(move |window, event| {
+                if let tauri::WindowEvent::Destroyed = event {
+                    window_events.remove_window(window.label());
+                }
+            })
             .invoke_handler(tauri::generate_handler![
                 register,
                 login,
+                complete_two_factor_login,
+                verify_email,
+                resend_verification_email,
                 get_current_user,
                 get_user_profile,
                 update_profile,
                 change_password,
 
+                begin_totp_enrollment,
+                confirm_totp_enrollment,
+                disable_totp,
+
+                create_personal_access_token,
+                list_personal_access_tokens,
+                revoke_personal_access_token,
+
                 create_question,
+                suggest_similar_questions,
                 get_question,
+                get_question_sidebar,
+                update_question,
+                get_code_theme_css,
                 list_questions,
+                close_question,
+                reopen_question,
 
                 create_answer,
                 get_question_answers,
@@ -123,8 +194,20 @@ fn main() {
 
                 create_tag,
                 get_tag,
+                get_tag_wiki,
+                get_tag_revisions,
+                edit_tag_wiki,
                 list_tags,
                 search_tags,
+                autocomplete_tags,
+                update_tag,
+                merge_tags,
+                delete_tag,
+                get_tag_audit_log,
+                suggest_tag_synonym,
+                vote_tag_synonym,
+                review_tag_synonym,
+                list_tag_synonyms,
 
                 create_vote,
                 get_vote_count,
@@ -133,7 +216,34 @@ fn main() {
                 get_comments,
                 update_comment,
                 delete_comment,
+                upvote_comment,
+                remove_comment_upvote,
                 
+                flag_post,
+                get_flag_queue,
+                resolve_flag,
+
+                get_review_summaries,
+                get_review_queue,
+                submit_review,
+
+                list_notifications,
+                get_unread_notification_count,
+                mark_notifications_read,
+                get_notification_preferences,
+                update_notification_preferences,
+
+                subscribe_to_question,
+                unsubscribe_from_question,
+
+                follow_question,
+                unfollow_question,
+                list_followed_questions,
+                get_tag_preferences,
+                set_tag_preference,
+                clear_tag_preference,
+                update_ignored_tag_display,
+
                 create_bookmark,
                 list_bookmarks,
                 get_bookmark,
@@ -0,0 +1,295 @@
+use ammonia::Builder;
+use pulldown_cmark::{html, CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+use syntect::highlighting::ThemeSet;
+use syntect::html::{css_for_theme_with_class_style, ClassStyle, ClassedHTMLGenerator};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+use syntect::util::LinesWithEndings;
+
+pub const RENDER_VERSION: i32 = 1;
+
+const ALLOWED_TAGS: &[&str] = &[
+    "a", "blockquote", "br", "code", "del", "em", "h1", "h2", "h3", "h4", "h5", "h6", "hr", "img",
+    "kbd", "li", "ol", "p", "pre", "span", "strong", "sub", "sup", "table", "tbody", "td", "th", "thead",
+    "tr", "ul",
+];
+const MATH_TAGS: &[&str] = &[
+    "annotation", "math", "menclose", "merror", "mfrac", "mi", "mn", "mo", "mover", "mpadded",
+    "mphantom", "mroot", "mrow", "ms", "mspace", "msqrt", "mstyle", "msub", "msubsup", "msup", "mtable",
+    "mtd", "mtext", "mtr", "munder", "munderover", "semantics",
+];
+const MATH_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("annotation", &["encoding"]),
+    ("math", &["display", "xmlns"]),
+    ("menclose", &["notation"]),
+    ("mfrac", &["linethickness"]),
+    ("mi", &["mathvariant"]),
+    ("mn", &["mathvariant"]),
+    ("mo", &["fence", "lspace", "mathvariant", "maxsize", "minsize", "rspace", "separator", "stretchy"]),
+    ("mover", &["accent"]),
+    ("mpadded", &["depth", "height", "lspace", "voffset", "width"]),
+    ("mspace", &["width"]),
+    ("mstyle", &["displaystyle", "mathcolor", "scriptlevel"]),
+    ("mtable", &["columnalign", "columnlines", "columnspacing", "rowalign", "rowlines", "rowspacing", "width"]),
+    ("mtd", &["columnalign", "rowalign"]),
+    ("mtext", &["mathvariant"]),
+    ("munder", &["accentunder"]),
+];
+const MATH_CLASS_PREFIX: &str = "katex";
+const MATH_MAX_EXPAND: i32 = 1000;
+const MATH_MAX_SIZE: f64 = 10.0;
+const URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+const CODE_CLASS_PREFIX: &str = "language-";
+const HIGHLIGHT_PREFIX: &str = "hl-";
+const HIGHLIGHT_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: HIGHLIGHT_PREFIX };
+const HIGHLIGHT_THEME: &str = "base16-ocean.dark";
+const LANGUAGE_ALIASES: &[(&str, &str)] = &[
+    ("bash", "sh"),
+    ("shell", "sh"),
+    ("zsh", "sh"),
+    ("golang", "go"),
+    ("jsx", "js"),
+    ("node.js", "js"),
+    ("reactjs", "js"),
+    ("ts", "js"),
+    ("tsx", "js"),
+    ("typescript", "js"),
+];
+
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+static THEME_CSS: LazyLock<String> = LazyLock::new(|| {
+    ThemeSet::load_defaults()
+        .themes
+        .get(HIGHLIGHT_THEME)
+        .and_then(|theme| css_for_theme_with_class_style(theme, HIGHLIGHT_STYLE).ok())
+        .unwrap_or_default()
+});
+
+static SANITIZER: LazyLock<Builder<'static>> = LazyLock::new(|| {
+    let mut builder = Builder::empty();
+    builder
+        .tags(ALLOWED_TAGS.iter().chain(MATH_TAGS).copied().collect())
+        .add_tag_attributes("a", ["href", "title"])
+        .add_tag_attributes("img", ["src", "alt", "title"])
+        .add_tag_attributes("ol", ["start"])
+        .add_tag_attributes("code", ["class"])
+        .add_tag_attributes("pre", ["class"])
+        .add_tag_attributes("span", ["class"])
+        .url_schemes(URL_SCHEMES.iter().copied().collect::<HashSet<_>>())
+        .link_rel(Some("noopener noreferrer nofollow"))
+        .attribute_filter(|element, attribute, value| match attribute {
+            "class" => {
+                let classes: Vec<&str> = value
+                    .split_whitespace()
+                    .filter(|class| {
+                        class.starts_with(HIGHLIGHT_PREFIX)
+                            || (element == "code" && class.starts_with(CODE_CLASS_PREFIX))
+                            || (element == "span" && class.starts_with(MATH_CLASS_PREFIX))
+                    })
+                    .collect();
+                (!classes.is_empty()).then(|| Cow::Owned(classes.join(" ")))
+            }
+            _ => Some(value.into()),
+        });
+    for (tag, attributes) in MATH_ATTRIBUTES {
+        builder.add_tag_attributes(tag, attributes.iter());
+    }
+    builder
+});
+
+struct CodeBlock<'a> {
+    kind: CodeBlockKind<'a>,
+    text: String,
+}
+
+pub fn render(source: &str, tags: &[String]) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_MATH);
+
+    let default_language = tags.iter().map(String::as_str).find(|tag| find_syntax(tag).is_some());
+
+    let mut events = Vec::new();
+    let mut code_block: Option<CodeBlock> = None;
+
+    for event in Parser::new_ext(source, options) {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                code_block = Some(CodeBlock { kind, text: String::new() });
+            }
+            Event::Text(text) => match code_block.as_mut() {
+                Some(block) => block.text.push_str(&text),
+                None => events.push(Event::Text(text)),
+            },
+            Event::End(TagEnd::CodeBlock) => {
+                if let Some(block) = code_block.take() {
+                    let language = match &block.kind {
+                        CodeBlockKind::Fenced(info) => info.split_whitespace().next(),
+                        CodeBlockKind::Indented => None,
+                    };
+
+                    match highlight(&block.text, language.or(default_language)) {
+                        Some(highlighted) => events.push(Event::Html(highlighted.into())),
+                        None => {
+                            events.push(Event::Start(Tag::CodeBlock(block.kind)));
+                            events.push(Event::Text(block.text.into()));
+                            events.push(Event::End(TagEnd::CodeBlock));
+                        }
+                    }
+                }
+            }
+            Event::InlineMath(source) => push_math(&mut events, source, false),
+            Event::DisplayMath(source) => push_math(&mut events, source, true),
+            event => events.push(event),
+        }
+    }
+
+    let mut unsafe_html = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, events.into_iter());
+
+    SANITIZER.clean(&unsafe_html).to_string()
+}
+
+pub fn theme_css() -> &'static str {
+    &THEME_CSS
+}
+
+fn push_math<'a>(events: &mut Vec<Event<'a>>, source: CowStr<'a>, display: bool) {
+    let mut options = katex::Opts::default();
+    options.set_output_type(katex::OutputType::Mathml);
+    options.set_display_mode(display);
+    options.set_throw_on_error(true);
+    options.set_max_expand(Some(MATH_MAX_EXPAND));
+    options.set_max_size(Some(MATH_MAX_SIZE));
+
+    match katex::render_with_opts(&source, &options) {
+        Ok(mathml) => events.push(Event::Html(mathml.into())),
+        Err(error) => {
+            events.push(Event::Html(format!("<span class=\"{MATH_CLASS_PREFIX}-error\">").into()));
+            events.push(Event::Code(source));
+            events.push(Event::Html(format!(" <span class=\"{MATH_CLASS_PREFIX}-error-message\">").into()));
+            events.push(Event::Text(math_error(error).into()));
+            events.push(Event::Html("</span></span>".into()));
+        }
+    }
+}
+
+fn math_error(error: katex::Error) -> String {
+    let detail = match error {
+        katex::Error::JsExecError(detail) => detail,
+        error => return error.to_string(),
+    };
+
+    detail
+        .split_once("KaTeX parse error: ")
+        .map_or(detail.as_str(), |(_, message)| message)
+        .trim_end_matches("\")")
+        .replace("\\u{332}", "")
+        .replace("\\\"", "\"")
+        .replace("\\\\", "\\")
+}
+
+fn find_syntax(language: &str) -> Option<&'static SyntaxReference> {
+    let language = language.to_lowercase();
+    let token = LANGUAGE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == language)
+        .map_or(language.as_str(), |(_, token)| token);
+
+    SYNTAXES.find_syntax_by_token(token)
+}
+
+fn highlight(code: &str, language: Option<&str>) -> Option<String> {
+    let language = language?;
+    let syntax = find_syntax(language)?;
+
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(syntax, &SYNTAXES, HIGHLIGHT_STYLE);
+    for line in LinesWithEndings::from(code) {
+        generator.parse_html_for_line_which_includes_newline(line).ok()?;
+    }
+
+    let language: String = language
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '#' | '-' | '.'))
+        .collect();
+
+    Some(format!(
+        "<pre class=\"{HIGHLIGHT_PREFIX}code\"><code class=\"{CODE_CLASS_PREFIX}{language}\">{}</code></pre>\n",
+        generator.finalize()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_strips_script_tags() {
+        let html = render("Hello <script>alert('xss')</script> world", &[]);
+
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("alert("));
+        assert!(html.contains("Hello"));
+    }
+
+    #[test]
+    fn render_strips_event_handler_attributes() {
+        let html = render("<img src=\"https://example.com/a.png\" onerror=\"alert(1)\">", &[]);
+
+        assert!(html.contains("<img src=\"https://example.com/a.png\""));
+        assert!(!html.contains("onerror"));
+    }
+
+    #[test]
+    fn render_drops_javascript_links() {
+        let html = render("[click](javascript:alert(1)) <a href=\"javascript:alert(2)\">raw</a>", &[]);
+
+        assert!(!html.contains("javascript:"));
+        assert!(html.contains("click"));
+        assert!(html.contains("raw"));
+    }
+
+    #[test]
+    fn render_escapes_html_inside_fenced_code() {
+        let plain = render("```\n<script>alert(1)</script>\n```", &[]);
+        assert!(!plain.contains("<script"));
+        assert!(plain.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+
+        let highlighted = render("```rust\n<script>alert(1)</script>\n```", &[]);
+        assert!(highlighted.contains("class=\"language-rust\""));
+        assert!(!highlighted.contains("<script"));
+        assert!(highlighted.contains("&lt;"));
+    }
+
+    #[test]
+    fn render_converts_valid_math_to_mathml() {
+        let html = render("Euler: $e^{i\\pi} + 1 = 0$", &[]);
+
+        assert!(html.contains("<math"));
+        assert!(html.contains("<msup>"));
+        assert!(!html.contains("katex-error"));
+    }
+
+    #[test]
+    fn render_reports_invalid_math_inline() {
+        let html = render("Broken: $\\frac{1}$", &[]);
+
+        assert!(!html.contains("<math"));
+        assert!(html.contains("<span class=\"katex-error\"><code>\\frac{1}</code>"));
+
+        let message = html
+            .split_once("<span class=\"katex-error-message\">")
+            .and_then(|(_, rest)| rest.split_once("</span>"))
+            .map(|(message, _)| message)
+            .expect("error message");
+        assert_eq!(
+            message,
+            "Unexpected end of input in a macro argument, expected '}' at end of input: \\frac{1}"
+        );
+    }
+}
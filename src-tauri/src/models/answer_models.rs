@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use validator::Validate;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AnswerResponse {
     pub id: i32,
     pub question_id: i32,
     pub content: String,
+    pub rendered_html: String,
     pub user_id: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -23,13 +25,15 @@ pub struct UserBrief {
     pub avatar_url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct CreateAnswerRequest {
     pub question_id: i32,
+    #[validate(custom(function = "super::validation::answer_body"))]
     pub content: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct UpdateAnswerRequest {
+    #[validate(custom(function = "super::validation::answer_body"))]
     pub content: String,
 }
@@ -1,12 +1,15 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use validator::Validate;
+
+use crate::entities::sea_orm_active_enums::TargetType;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BookmarkResponse {
     pub id: i32,
     pub user_id: i32,
     pub target_id: i32,
-    pub target_type: String,
+    pub target_type: TargetType,
     pub created_at: DateTime<Utc>,
     pub title: String,
     pub note: Option<String>,
@@ -17,7 +20,7 @@ pub struct BookmarkWithContentResponse {
     pub id: i32,
     pub user_id: i32,
     pub target_id: i32,
-    pub target_type: String,
+    pub target_type: TargetType,
     pub created_at: DateTime<Utc>,
     pub title: String,
     pub note: Option<String>,
@@ -32,16 +35,20 @@ pub struct BookmarkContentSnapshot {
     pub author_id: i32,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct CreateBookmarkRequest {
     pub target_id: i32,
-    pub target_type: String,
+    pub target_type: TargetType,
+    #[validate(length(max = 150, message = "Title must be at most 150 characters"))]
     pub title: Option<String>,
+    #[validate(length(max = 1000, message = "Notes must be at most 1000 characters"))]
     pub note: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct UpdateBookmarkRequest {
+    #[validate(length(max = 150, message = "Title must be at most 150 characters"))]
     pub title: Option<String>,
+    #[validate(length(max = 1000, message = "Notes must be at most 1000 characters"))]
     pub note: Option<String>,
 } 
\ No newline at end of file
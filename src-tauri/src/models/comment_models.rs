@@ -1,19 +1,28 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use validator::Validate;
+
+use crate::entities::sea_orm_active_enums::TargetType;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CommentResponse {
     pub id: i32,
     pub content: String,
+    pub rendered_html: String,
     pub user_id: i32,
     pub target_id: i32,
-    pub target_type: String,
+    pub target_type: TargetType,
+    pub parent_comment_id: Option<i32>,
+    pub depth: i32,
+    pub hidden: bool,
+    pub score: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub author: UserBrief,
+    pub mentions: Vec<UserBrief>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserBrief {
     pub id: i32,
     pub username: String,
@@ -22,14 +31,17 @@ pub struct UserBrief {
     pub avatar_url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct CreateCommentRequest {
+    #[validate(custom(function = "super::validation::comment_body"))]
     pub content: String,
     pub target_id: i32,
-    pub target_type: String,
+    pub target_type: TargetType,
+    pub parent_comment_id: Option<i32>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct UpdateCommentRequest {
+    #[validate(custom(function = "super::validation::comment_body"))]
     pub content: String,
-} 
\ No newline at end of file
+}
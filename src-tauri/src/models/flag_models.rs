@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use validator::Validate;
+
+use crate::entities::sea_orm_active_enums::{FlagReason, FlagStatus, ModerationAction, PostType};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserBrief {
+    pub id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub reputation: i32,
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateFlagRequest {
+    pub post_type: PostType,
+    pub post_id: i32,
+    pub reason: FlagReason,
+    #[validate(length(max = 500, message = "Flag details must be at most 500 characters"))]
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ResolveFlagRequest {
+    pub status: FlagStatus,
+    pub action: Option<ModerationAction>,
+    #[validate(length(min = 15, max = 150, message = "Title must be between 15 and 150 characters"))]
+    pub title: Option<String>,
+    #[validate(
+        custom(function = "super::validation::not_blank"),
+        length(max = 30000, message = "Content must be at most 30000 characters")
+    )]
+    pub content: Option<String>,
+    #[validate(length(max = 500, message = "Notes must be at most 500 characters"))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FlagQueueQuery {
+    pub page: Option<u64>,
+    pub per_page: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FlagResponse {
+    pub id: i32,
+    pub post_type: PostType,
+    pub post_id: i32,
+    pub reason: FlagReason,
+    pub text: Option<String>,
+    pub status: FlagStatus,
+    pub flagger: UserBrief,
+    pub weight: f64,
+    pub resolved_by: Option<i32>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolution_action: Option<ModerationAction>,
+    pub resolution_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FlagReasonCount {
+    pub reason: FlagReason,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FlaggedPostResponse {
+    pub post_type: PostType,
+    pub post_id: i32,
+    pub title: String,
+    pub preview: String,
+    pub author: Option<UserBrief>,
+    pub is_hidden: bool,
+    pub is_locked: bool,
+    pub pending_count: u64,
+    pub total_count: u64,
+    pub pending_weight: f64,
+    pub reasons: Vec<FlagReasonCount>,
+    pub history: Vec<FlagResponse>,
+}
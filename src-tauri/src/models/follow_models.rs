@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+
+use crate::entities::sea_orm_active_enums::TagPreference;
+
+#[derive(Debug, Serialize)]
+pub struct FollowedQuestionResponse {
+    pub question_id: i32,
+    pub title: String,
+    pub is_answered: bool,
+    pub followed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetTagPreferenceRequest {
+    pub tag_id: i32,
+    pub preference: TagPreference,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateIgnoredTagDisplayRequest {
+    pub hide_ignored_tags: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagBrief {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagPreferencesResponse {
+    pub watched: Vec<TagBrief>,
+    pub ignored: Vec<TagBrief>,
+    pub hide_ignored_tags: bool,
+}
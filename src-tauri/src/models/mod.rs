@@ -5,3 +5,12 @@ pub mod vote_models;
 pub mod tag_models;
 pub mod comment_models;
 pub mod bookmark_models;
+pub mod two_factor_models;
+pub mod personal_access_token_models;
+pub mod validation;
+
+pub mod flag_models;
+pub mod review_models;
+pub mod notification_models;
+pub mod follow_models;
+pub mod tag_synonym_models;
\ No newline at end of file
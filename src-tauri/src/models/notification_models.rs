@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use validator::Validate;
+
+use crate::entities::sea_orm_active_enums::{NotificationType, PostType};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserBrief {
+    pub id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub reputation: i32,
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotificationQuery {
+    pub page: Option<u64>,
+    pub per_page: Option<u64>,
+    pub unread_only: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct MarkNotificationsReadRequest {
+    #[validate(length(min = 1, max = 100, message = "Provide between 1 and 100 notification ids"))]
+    pub ids: Option<Vec<i32>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotificationPreference {
+    pub notification_type: NotificationType,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateNotificationPreferencesRequest {
+    #[validate(length(min = 1, message = "At least one preference is required"))]
+    pub preferences: Vec<NotificationPreference>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NotificationResponse {
+    pub id: i32,
+    pub notification_type: NotificationType,
+    pub actor: Option<UserBrief>,
+    pub post_type: PostType,
+    pub post_id: i32,
+    pub question_id: i32,
+    pub reputation_change: i32,
+    pub is_read: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnreadCountResponse {
+    pub unread: u64,
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use validator::Validate;
+
+use crate::auth::Scope;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PersonalAccessTokenResponse {
+    pub id: i32,
+    pub name: String,
+    pub token_prefix: String,
+    pub scopes: Vec<Scope>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatedPersonalAccessTokenResponse {
+    pub token: String,
+    pub details: PersonalAccessTokenResponse,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreatePersonalAccessTokenRequest {
+    #[validate(
+        custom(function = "super::validation::not_blank"),
+        length(max = 100, message = "Token name must be at most 100 characters")
+    )]
+    pub name: String,
+    #[validate(length(min = 1, message = "At least one scope is required"))]
+    pub scopes: Vec<Scope>,
+    #[validate(range(min = 1, max = 366, message = "Expiry must be between 1 and 366 days"))]
+    pub expires_in_days: Option<i64>,
+}
@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use validator::Validate;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QuestionResponse {
     pub id: i32,
     pub title: String,
     pub content: String,
+    pub rendered_html: String,
     pub user_id: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -16,6 +18,8 @@ pub struct QuestionResponse {
     pub author: UserBrief,
     pub answer_count: i32,
     pub vote_count: i32,
+    pub is_watched: bool,
+    pub is_ignored: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -27,25 +31,72 @@ pub struct UserBrief {
     pub avatar_url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct CreateQuestionRequest {
+    #[validate(custom(function = "super::validation::question_title"))]
     pub title: String,
+    #[validate(custom(function = "super::validation::question_body"))]
     pub content: String,
+    #[validate(
+        length(min = 1, message = "At least one tag is required"),
+        custom(function = "super::validation::tag_list")
+    )]
     pub tags: Vec<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct UpdateQuestionRequest {
+    #[validate(custom(function = "super::validation::question_title"))]
     pub title: Option<String>,
+    #[validate(custom(function = "super::validation::question_body"))]
     pub content: Option<String>,
+    #[validate(
+        length(min = 1, message = "At least one tag is required"),
+        custom(function = "super::validation::tag_list")
+    )]
     pub tags: Option<Vec<String>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct QuestionQuery {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
     pub sort_by: Option<String>,
     pub tag: Option<String>,
     pub search: Option<String>,
-} 
\ No newline at end of file
+    pub boost_watched: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RelatedQuestion {
+    pub id: i32,
+    pub title: String,
+    pub answer_count: i32,
+    pub is_answered: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuestionSidebarResponse {
+    pub related: Vec<RelatedQuestion>,
+    pub linked: Vec<RelatedQuestion>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SimilarQuestionsRequest {
+    #[validate(length(max = 150, message = "Title must be at most 150 characters"))]
+    pub title: String,
+    #[validate(length(max = 30000, message = "Body must be at most 30000 characters"))]
+    pub content: Option<String>,
+    #[validate(length(max = 5, message = "At most 5 tags are allowed"))]
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SimilarQuestion {
+    pub id: i32,
+    pub title: String,
+    pub score: f64,
+    pub answer_count: i32,
+    pub is_answered: bool,
+    pub is_closed: bool,
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use validator::Validate;
+
+use crate::entities::sea_orm_active_enums::{PostType, ReviewAction, ReviewQueue, ReviewStatus};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserBrief {
+    pub id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub reputation: i32,
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewQueueQuery {
+    pub queue: ReviewQueue,
+    pub page: Option<u64>,
+    pub per_page: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SubmitReviewRequest {
+    pub action: ReviewAction,
+    #[validate(length(min = 15, max = 150, message = "Title must be between 15 and 150 characters"))]
+    pub title: Option<String>,
+    #[validate(
+        custom(function = "super::validation::not_blank"),
+        length(min = 30, max = 30000, message = "Content must be between 30 and 30000 characters")
+    )]
+    pub content: Option<String>,
+    #[validate(length(max = 500, message = "Notes must be at most 500 characters"))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReviewActionCount {
+    pub action: ReviewAction,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReviewTaskResponse {
+    pub id: i32,
+    pub queue: ReviewQueue,
+    pub post_type: PostType,
+    pub post_id: i32,
+    pub question_id: i32,
+    pub title: String,
+    pub content: String,
+    pub author: UserBrief,
+    pub status: ReviewStatus,
+    pub outcome: Option<ReviewAction>,
+    pub decisions: Vec<ReviewActionCount>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReviewQueueSummary {
+    pub queue: ReviewQueue,
+    pub open_count: u64,
+}
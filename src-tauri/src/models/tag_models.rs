@@ -1,22 +1,116 @@
 use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use validator::Validate;
+
+use crate::entities::sea_orm_active_enums::TagAuditAction;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TagResponse {
     pub id: i32,
     pub name: String,
-    pub description: Option<String>,
+    pub excerpt: Option<String>,
     pub question_count: i32,
+    pub questions_this_week: i32,
+    pub questions_this_month: i32,
+    pub top_answerers: Vec<TopAnswerer>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopAnswerer {
+    pub user: UserBrief,
+    pub answer_count: i32,
+}
+
+#[derive(Debug, Deserialize, Validate)]
 pub struct CreateTagRequest {
+    #[validate(custom(function = "super::validation::tag_name"))]
     pub name: String,
-    pub description: Option<String>,
+    #[validate(length(max = 500, message = "Excerpt must be at most 500 characters"))]
+    pub excerpt: Option<String>,
+    #[validate(length(max = 30000, message = "Wiki must be at most 30000 characters"))]
+    pub wiki: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
-#[allow(dead_code)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct UpdateTagRequest {
+    #[validate(custom(function = "super::validation::tag_name"))]
     pub name: Option<String>,
-    pub description: Option<String>,
+    #[validate(length(max = 500, message = "Excerpt must be at most 500 characters"))]
+    pub excerpt: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct EditTagWikiRequest {
+    #[validate(length(max = 500, message = "Excerpt must be at most 500 characters"))]
+    pub excerpt: Option<String>,
+    #[validate(length(max = 30000, message = "Wiki must be at most 30000 characters"))]
+    pub wiki: Option<String>,
+    #[validate(length(max = 300, message = "Edit summary must be at most 300 characters"))]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagWikiResponse {
+    pub tag_id: i32,
+    pub name: String,
+    pub excerpt: Option<String>,
+    pub wiki: Option<String>,
+    pub revision: i32,
+    pub last_edited_by: Option<UserBrief>,
+    pub last_edited_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagRevisionResponse {
+    pub revision: i32,
+    pub excerpt: Option<String>,
+    pub wiki: Option<String>,
+    pub comment: Option<String>,
+    pub editor: Option<UserBrief>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TagAutocompleteQuery {
+    pub term: String,
+    pub limit: Option<u64>,
+    pub exclude: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagSuggestion {
+    pub id: i32,
+    pub name: String,
+    pub excerpt: Option<String>,
+    pub question_count: i32,
+    pub synonym: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TagAuditLogQuery {
+    pub tag_id: Option<i32>,
+    pub page: Option<u64>,
+    pub per_page: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserBrief {
+    pub id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub reputation: i32,
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagAuditLogResponse {
+    pub id: i32,
+    pub action: TagAuditAction,
+    pub moderator: Option<UserBrief>,
+    pub tag_id: i32,
+    pub tag_name: String,
+    pub target_tag_id: Option<i32>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub created_at: DateTime<Utc>,
 }
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use validator::Validate;
+
+use crate::entities::sea_orm_active_enums::{SynonymStatus, VoteType};
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SuggestTagSynonymRequest {
+    #[validate(custom(function = "super::validation::tag_name"))]
+    pub source_name: String,
+    pub target_tag_id: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TagSynonymQuery {
+    pub tag_id: Option<i32>,
+    pub status: Option<SynonymStatus>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagSynonymResponse {
+    pub id: i32,
+    pub source_name: String,
+    pub target_tag_id: i32,
+    pub target_name: String,
+    pub status: SynonymStatus,
+    pub score: i32,
+    pub suggested_by: Option<i32>,
+    pub user_vote: Option<VoteType>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
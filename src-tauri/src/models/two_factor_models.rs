@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TotpEnrollmentResponse {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ConfirmTotpRequest {
+    #[validate(custom(function = "super::validation::recovery_or_totp_code"))]
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct DisableTotpRequest {
+    #[validate(length(min = 1, message = "Password is required"))]
+    pub password: String,
+    #[validate(custom(function = "super::validation::recovery_or_totp_code"))]
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct TwoFactorLoginRequest {
+    #[validate(length(min = 1, message = "Challenge token is required"))]
+    pub challenge_token: String,
+    #[validate(custom(function = "super::validation::recovery_or_totp_code"))]
+    pub code: String,
+}
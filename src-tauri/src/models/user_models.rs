@@ -1,16 +1,32 @@
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate)]
 pub struct LoginRequest {
+    #[validate(length(min = 1, max = 254, message = "Email is required"))]
     pub email: String,
+    #[validate(length(min = 1, max = 1024, message = "Password is required"))]
     pub password: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate)]
 pub struct RegisterRequest {
+    #[validate(
+        length(min = 3, max = 30, message = "Username must be between 3 and 30 characters"),
+        custom(function = "super::validation::username")
+    )]
     pub username: String,
+    #[validate(
+        length(max = 254, message = "Email must be at most 254 characters"),
+        email(message = "Enter a valid email address")
+    )]
     pub email: String,
+    #[validate(length(max = 128, message = "Password must be at most 128 characters"))]
     pub password: String,
+    #[validate(
+        custom(function = "super::validation::not_blank"),
+        length(max = 50, message = "Display name must be at most 50 characters")
+    )]
     pub display_name: String,
 }
 
@@ -23,6 +39,9 @@ pub struct UserResponse {
     pub bio: Option<String>,
     pub reputation: i32,
     pub avatar_url: Option<String>,
+    pub email_verified: bool,
+    pub two_factor_enabled: bool,
+    pub is_moderator: bool,
     pub created_at: String,
 }
 
@@ -33,21 +52,41 @@ pub struct AuthResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct ErrorResponse {
-    pub message: String,
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum LoginResponse {
+    Authenticated(AuthResponse),
+    TwoFactorRequired { challenge_token: String },
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct VerifyEmailRequest {
+    #[validate(length(min = 1, max = 128, message = "Verification token is required"))]
+    pub token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
 pub struct UpdateProfileRequest {
+    #[validate(
+        custom(function = "super::validation::not_blank"),
+        length(max = 50, message = "Display name must be at most 50 characters")
+    )]
     pub display_name: Option<String>,
+    #[validate(length(max = 3000, message = "Bio must be at most 3000 characters"))]
     pub bio: Option<String>,
+    #[validate(
+        length(max = 2048, message = "Avatar URL must be at most 2048 characters"),
+        url(message = "Avatar URL must be a valid URL")
+    )]
     pub avatar_url: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate)]
 pub struct ChangePasswordRequest {
+    #[validate(length(min = 1, message = "Current password is required"))]
     pub current_password: String,
+    #[validate(length(max = 128, message = "Password must be at most 128 characters"))]
     pub new_password: String,
+    #[validate(must_match(other = "new_password", message = "Passwords do not match"))]
     pub confirm_password: String,
 }
 
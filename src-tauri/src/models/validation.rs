@@ -0,0 +1,125 @@
+use std::borrow::Cow;
+use validator::{ValidationError, ValidationErrors};
+
+pub const MAX_TAGS: usize = 5;
+pub const MAX_TAG_LENGTH: usize = 35;
+
+fn error(code: &'static str, message: impl Into<Cow<'static, str>>) -> ValidationError {
+    ValidationError::new(code).with_message(message.into())
+}
+
+pub fn not_blank(value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        return Err(error("blank", "Cannot be empty"));
+    }
+    Ok(())
+}
+
+fn length_between(value: &str, min: usize, max: usize, message: &'static str) -> Result<(), ValidationError> {
+    let length = value.chars().count();
+    if length < min || length > max {
+        return Err(error("length", message));
+    }
+    Ok(())
+}
+
+pub fn question_title(value: &str) -> Result<(), ValidationError> {
+    not_blank(value)?;
+    length_between(value, 15, 150, "Title must be between 15 and 150 characters")
+}
+
+pub fn question_body(value: &str) -> Result<(), ValidationError> {
+    not_blank(value)?;
+    length_between(value, 30, 30000, "Body must be between 30 and 30000 characters")
+}
+
+pub fn answer_body(value: &str) -> Result<(), ValidationError> {
+    not_blank(value)?;
+    length_between(value, 30, 30000, "Answer must be between 30 and 30000 characters")
+}
+
+pub fn comment_body(value: &str) -> Result<(), ValidationError> {
+    not_blank(value)?;
+    length_between(value, 15, 600, "Comments must be between 15 and 600 characters")
+}
+
+pub fn check(field: &'static str, result: Result<(), ValidationError>) -> Result<(), ValidationErrors> {
+    result.map_err(|error| {
+        let mut errors = ValidationErrors::new();
+        errors.add(field, error);
+        errors
+    })
+}
+
+pub fn username(value: &str) -> Result<(), ValidationError> {
+    if !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(error(
+            "username",
+            "Only letters, digits, underscores and hyphens are allowed",
+        ));
+    }
+    if !value.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return Err(error("username", "Must start with a letter or digit"));
+    }
+    Ok(())
+}
+
+pub fn normalize_tag_name(value: &str) -> String {
+    let mut normalized = String::with_capacity(value.len());
+    for c in value.trim().chars().flat_map(char::to_lowercase) {
+        let c = if c.is_whitespace() || c == '_' { '-' } else { c };
+        if c == '-' && (normalized.is_empty() || normalized.ends_with('-')) {
+            continue;
+        }
+        normalized.push(c);
+    }
+    while normalized.ends_with('-') {
+        normalized.pop();
+    }
+    normalized
+}
+
+pub fn tag_name(value: &str) -> Result<(), ValidationError> {
+    let value = normalize_tag_name(value);
+    if value.is_empty() || value.len() > MAX_TAG_LENGTH {
+        return Err(error(
+            "tag_name",
+            format!("Tag names must be between 1 and {} characters", MAX_TAG_LENGTH),
+        ));
+    }
+    if !value
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '.' | '+' | '#'))
+    {
+        return Err(error(
+            "tag_name",
+            format!("'{}' may only contain letters, digits and - . + #", value),
+        ));
+    }
+    if !value.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return Err(error("tag_name", format!("'{}' must start with a letter or digit", value)));
+    }
+    Ok(())
+}
+
+pub fn tag_list(tags: &[String]) -> Result<(), ValidationError> {
+    if tags.len() > MAX_TAGS {
+        return Err(error("tags", format!("At most {} tags are allowed", MAX_TAGS)));
+    }
+    let normalized: Vec<String> = tags.iter().map(|tag| normalize_tag_name(tag)).collect();
+    for (index, tag) in normalized.iter().enumerate() {
+        if normalized[..index].contains(tag) {
+            return Err(error("tags", format!("'{}' is listed more than once", tag)));
+        }
+        tag_name(tag)?;
+    }
+    Ok(())
+}
+
+pub fn recovery_or_totp_code(value: &str) -> Result<(), ValidationError> {
+    let normalized: String = value.chars().filter(|c| !c.is_whitespace() && *c != '-').collect();
+    if normalized.is_empty() || normalized.len() > 10 || !normalized.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(error("code", "Enter a 6 digit code or a recovery code"));
+    }
+    Ok(())
+}
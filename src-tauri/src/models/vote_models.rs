@@ -1,21 +1,24 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use validator::Validate;
+
+use crate::entities::sea_orm_active_enums::{TargetType, VoteType};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct VoteResponse {
     pub id: i32,
     pub user_id: i32,
-    pub vote_type: String,
+    pub vote_type: VoteType,
     pub target_id: i32,
-    pub target_type: String,
+    pub target_type: TargetType,
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct CreateVoteRequest {
     pub target_id: i32,
-    pub target_type: String,
-    pub vote_type: String,
+    pub target_type: TargetType,
+    pub vote_type: VoteType,
 }
 
 #[derive(Debug, Serialize)]
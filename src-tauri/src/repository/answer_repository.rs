@@ -1,15 +1,25 @@
 use sea_orm::*;
-use crate::entities::{answers, users, votes};
+use crate::config::ReviewConfig;
+use crate::entities::{answers, questions, users, votes};
+use crate::entities::sea_orm_active_enums::{NotificationType, PostType, TargetType};
 use crate::models::answer_models::{AnswerResponse, UserBrief};
+use crate::models::validation;
+use crate::repository::follow_repository::FollowRepository;
+use crate::repository::notification_repository::NotificationRepository;
+use crate::repository::question_repository::QuestionRepository;
+use crate::repository::review_repository::ReviewRepository;
+use crate::events::{ActivityEvent, EventBus};
+use crate::markdown;
 use crate::error::{Result, AppError};
 
 pub struct AnswerRepository {
     db: DatabaseConnection,
+    events: EventBus,
 }
 
 impl AnswerRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: DatabaseConnection, events: EventBus) -> Self {
+        Self { db, events }
     }
 
     pub async fn create_answer(
@@ -17,20 +27,116 @@ impl AnswerRepository {
         question_id: i32,
         user_id: i32,
         content: String,
+        reviews: &ReviewConfig,
     ) -> Result<AnswerResponse> {
+        let question = questions::Entity::find_by_id(question_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
+
+        if question.locked_at.is_some() {
+            return Err(AppError::Forbidden("This question is locked and cannot receive new answers".to_string()));
+        }
+
+        let txn = self.db.begin().await?;
+
+        let tag_names = QuestionRepository::tag_names(&txn, question.id).await?;
+
         let answer = answers::ActiveModel {
             question_id: Set(question_id),
             user_id: Set(user_id),
+            rendered_html: Set(Some(markdown::render(&content, &tag_names))),
+            render_version: Set(markdown::RENDER_VERSION),
             content: Set(content),
             created_at: Set(chrono::Utc::now()),
             updated_at: Set(chrono::Utc::now()),
             is_accepted: Set(0),
             ..Default::default()
-        }.insert(&self.db).await?;
+        }.insert(&txn).await?;
+
+        NotificationRepository::notify_followers(
+            &txn,
+            question.id,
+            Some(user_id),
+            NotificationType::Answer,
+            PostType::Answer,
+            answer.id,
+            &[],
+        ).await?;
+
+        QuestionRepository::sync_links(&txn, PostType::Answer, answer.id, question.id, &answer.content).await?;
+        FollowRepository::follow(&txn, user_id, question.id).await?;
+        ReviewRepository::enqueue_post(&txn, PostType::Answer, answer.id, reviews.late_answer_days).await?;
+
+        txn.commit().await?;
+
+        self.events.publish(ActivityEvent::AnswerCreated {
+            question_id: question.id,
+            answer_id: answer.id,
+        });
 
         self.get_answer_by_id(answer.id).await
     }
 
+    pub async fn edit(
+        txn: &DatabaseTransaction,
+        editor_id: i32,
+        answer_id: i32,
+        content: String,
+    ) -> Result<()> {
+        validation::check("content", validation::answer_body(&content))?;
+
+        let question_id = answers::Entity::find_by_id(answer_id)
+            .one(txn)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Answer not found".to_string()))?
+            .question_id;
+        let tag_names = QuestionRepository::tag_names(txn, question_id).await?;
+
+        let answer = answers::ActiveModel {
+            id: Set(answer_id),
+            rendered_html: Set(Some(markdown::render(&content, &tag_names))),
+            render_version: Set(markdown::RENDER_VERSION),
+            content: Set(content),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }.update(txn).await?;
+
+        QuestionRepository::sync_links(txn, PostType::Answer, answer_id, question_id, &answer.content).await?;
+
+        NotificationRepository::notify_followers(
+            txn,
+            question_id,
+            Some(editor_id),
+            NotificationType::Edit,
+            PostType::Answer,
+            answer_id,
+            &[],
+        ).await
+    }
+
+    pub async fn render_for_question<C: ConnectionTrait>(
+        conn: &C,
+        question_id: i32,
+        tag_names: &[String],
+    ) -> Result<()> {
+        let answers = answers::Entity::find()
+            .filter(answers::Column::QuestionId.eq(question_id))
+            .all(conn)
+            .await?;
+
+        for answer in answers {
+            answers::ActiveModel {
+                id: Set(answer.id),
+                rendered_html: Set(Some(markdown::render(&answer.content, tag_names))),
+                render_version: Set(markdown::RENDER_VERSION),
+                ..Default::default()
+            }.update(conn).await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn get_answer_by_id(&self, id: i32) -> Result<AnswerResponse> {
         let answer = answers::Entity::find_by_id(id)
             .one(&self.db)
@@ -62,17 +168,26 @@ impl AnswerRepository {
 
         let votes = votes::Entity::find()
             .filter(votes::Column::TargetId.eq(answer.id))
-            .filter(votes::Column::TargetType.eq("answer"))
+            .filter(votes::Column::TargetType.eq(TargetType::Answer))
             .all(&self.db)
             .await?;
 
         let vote_count = votes.iter().fold(0, |acc, vote| {
-            acc + if vote.vote_type == "up" { 1 } else { -1 }
+            acc + vote.vote_type.score()
         });
 
+        let rendered_html = match answer.rendered_html {
+            Some(rendered_html) => rendered_html,
+            None => {
+                let tag_names = QuestionRepository::tag_names(&self.db, answer.question_id).await?;
+                markdown::render(&answer.content, &tag_names)
+            }
+        };
+
         Ok(AnswerResponse {
             id: answer.id,
             question_id: answer.question_id,
+            rendered_html,
             content: answer.content,
             user_id: answer.user_id,
             created_at: answer.created_at,
@@ -89,9 +204,7 @@ impl AnswerRepository {
         })
     }
 
-    pub async fn accept_answer(&self, answer_id: i32) -> Result<()> {
-        use crate::entities::questions;
-        
+    pub async fn accept_answer(&self, answer_id: i32, user_id: i32) -> Result<()> {
         let txn = self.db.begin().await?;
         
         let answer = answers::Entity::find_by_id(answer_id)
@@ -103,7 +216,10 @@ impl AnswerRepository {
             .one(&txn)
             .await?
             .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
-            
+
+        if question.user_id != user_id {
+            return Err(AppError::Forbidden("Only the question author can accept an answer".to_string()));
+        }
         
         answers::Entity::update_many()
             .filter(answers::Column::QuestionId.eq(answer.question_id))
@@ -148,8 +264,35 @@ impl AnswerRepository {
             .update(&txn)
             .await?;
         }
+
+        if answer.is_accepted == 0 {
+            NotificationRepository::notify(
+                &txn,
+                answer.user_id,
+                Some(user_id),
+                NotificationType::Accepted,
+                PostType::Answer,
+                answer.id,
+                question.id,
+            ).await?;
+
+            NotificationRepository::notify_followers(
+                &txn,
+                question.id,
+                Some(user_id),
+                NotificationType::Solved,
+                PostType::Answer,
+                answer.id,
+                &[answer.user_id],
+            ).await?;
+        }
         
         txn.commit().await?;
+
+        self.events.publish(ActivityEvent::AnswerAccepted {
+            question_id: question.id,
+            answer_id: answer.id,
+        });
         
         Ok(())
     }
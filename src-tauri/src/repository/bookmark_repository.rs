@@ -1,5 +1,6 @@
 use sea_orm::*;
 use crate::entities::{bookmarks, users, questions, answers};
+use crate::entities::sea_orm_active_enums::TargetType;
 use crate::models::bookmark_models::{BookmarkResponse, BookmarkWithContentResponse, BookmarkContentSnapshot};
 use crate::error::{Result, AppError};
 
@@ -16,13 +17,13 @@ impl BookmarkRepository {
         &self,
         user_id: i32,
         target_id: i32,
-        target_type: String,
+        target_type: TargetType,
         title: Option<String>,
         note: Option<String>,
     ) -> Result<BookmarkResponse> {
         let bookmark_title = match &title {
             Some(t) => t.clone(),
-            None => format!("Bookmark for {} {}", target_type, target_id),
+            None => format!("Bookmark for {} {}", target_type.as_str(), target_id),
         };
 
         let bookmark = bookmarks::ActiveModel {
@@ -75,7 +76,7 @@ impl BookmarkRepository {
             .await?
             .ok_or_else(|| AppError::NotFound("Bookmark not found".to_string()))?;
 
-        let content_snapshot = self.get_content_snapshot(bookmark.target_id, &bookmark.target_type).await?;
+        let content_snapshot = self.get_content_snapshot(bookmark.target_id, bookmark.target_type).await?;
 
         Ok(BookmarkWithContentResponse {
             id: bookmark.id,
@@ -141,16 +142,16 @@ impl BookmarkRepository {
         Ok(())
     }
 
-    async fn get_content_title(&self, target_id: i32, target_type: &str) -> Result<String> {
+    async fn get_content_title(&self, target_id: i32, target_type: TargetType) -> Result<String> {
         match target_type {
-            "question" => {
+            TargetType::Question => {
                 let question = questions::Entity::find_by_id(target_id)
                     .one(&self.db)
                     .await?
                     .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
                 Ok(question.title)
             },
-            "answer" => {
+            TargetType::Answer => {
                 let answer = answers::Entity::find_by_id(target_id)
                     .one(&self.db)
                     .await?
@@ -163,13 +164,12 @@ impl BookmarkRepository {
 
                 Ok(format!("Answer to: {}", question.title))
             },
-            _ => Err(AppError::Internal(format!("Unsupported target type: {}", target_type))),
         }
     }
 
-    async fn get_content_snapshot(&self, target_id: i32, target_type: &str) -> Result<BookmarkContentSnapshot> {
+    async fn get_content_snapshot(&self, target_id: i32, target_type: TargetType) -> Result<BookmarkContentSnapshot> {
         match target_type {
-            "question" => {
+            TargetType::Question => {
                 let question = questions::Entity::find_by_id(target_id)
                     .one(&self.db)
                     .await?
@@ -187,7 +187,7 @@ impl BookmarkRepository {
                     author_id: user.id,
                 })
             },
-            "answer" => {
+            TargetType::Answer => {
                 let answer = answers::Entity::find_by_id(target_id)
                     .one(&self.db)
                     .await?
@@ -210,7 +210,6 @@ impl BookmarkRepository {
                     author_id: user.id,
                 })
             },
-            _ => Err(AppError::Internal(format!("Unsupported target type: {}", target_type))),
         }
     }
 
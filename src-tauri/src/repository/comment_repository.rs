@@ -1,74 +1,129 @@
 use sea_orm::*;
-use crate::entities::{users, comments};
+use std::collections::{HashMap, HashSet};
+use crate::entities::{answers, comment_mentions, comment_votes, comments, questions, users};
+use crate::entities::sea_orm_active_enums::{NotificationType, PostType, TargetType};
 use crate::models::comment_models::{CommentResponse, UserBrief};
+use crate::models::validation;
+use crate::repository::notification_repository::NotificationRepository;
+use crate::events::{ActivityEvent, EventBus};
+use crate::markdown;
 use crate::error::{Result, AppError};
 
 pub struct CommentRepository {
     db: DatabaseConnection,
+    events: EventBus,
 }
 
 impl CommentRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: DatabaseConnection, events: EventBus) -> Self {
+        Self { db, events }
     }
 
     pub async fn create_comment(
         &self,
         user_id: i32,
         target_id: i32,
-        target_type: String,
+        target_type: TargetType,
+        parent_comment_id: Option<i32>,
         content: String,
+        max_depth: i32,
     ) -> Result<CommentResponse> {
         if content.trim().is_empty() {
-            return Err(AppError::Internal("Comment content cannot be empty".to_string()));
+            return Err(AppError::validation("content", "Comment content cannot be empty"));
         }
 
+        let (target_author_id, question_id) = self.ensure_target_exists(target_id, target_type).await?;
+
+        let (depth, parent_author_id) = match parent_comment_id {
+            Some(parent_id) => {
+                let parent = comments::Entity::find_by_id(parent_id)
+                    .one(&self.db)
+                    .await?
+                    .ok_or_else(|| AppError::validation("parent_comment_id", "The comment being replied to does not exist"))?;
+
+                if parent.target_id != target_id || parent.target_type != target_type {
+                    return Err(AppError::validation(
+                        "parent_comment_id",
+                        "Replies must be posted on the same question or answer as their parent",
+                    ));
+                }
+
+                if parent.depth >= max_depth {
+                    return Err(AppError::validation(
+                        "parent_comment_id",
+                        format!("Replies cannot be nested more than {} levels deep", max_depth),
+                    ));
+                }
+
+                (parent.depth + 1, Some(parent.user_id))
+            }
+            None => (0, None),
+        };
+
         let now = chrono::Utc::now();
-        
-        
-        let comment_model = comments::ActiveModel {
+        let txn = self.db.begin().await?;
+
+        let comment = comments::ActiveModel {
+            rendered_html: Set(Some(markdown::render(&content, &[]))),
+            render_version: Set(markdown::RENDER_VERSION),
             content: Set(content.clone()),
             user_id: Set(user_id),
             target_id: Set(target_id),
-            target_type: Set(target_type.clone()),
+            target_type: Set(target_type),
+            parent_comment_id: Set(parent_comment_id),
+            depth: Set(depth),
             created_at: Set(now),
             updated_at: Set(now),
             ..Default::default()
-        };
-        
-        let comment = comment_model.insert(&self.db).await?;
+        }.insert(&txn).await?;
+
+        let mentions = self.sync_mentions(&txn, comment.id, user_id, &comment.content).await?;
+
+        let mut recipients: Vec<(i32, NotificationType)> = mentions
+            .iter()
+            .map(|user| (user.id, NotificationType::Mention))
+            .collect();
+        if let Some(parent_author_id) = parent_author_id {
+            recipients.push((parent_author_id, NotificationType::Reply));
+        }
+        recipients.push((target_author_id, NotificationType::Comment));
+
+        let mut notified = HashSet::new();
+        for (recipient_id, notification_type) in recipients {
+            if notified.insert(recipient_id) {
+                NotificationRepository::notify(
+                    &txn,
+                    recipient_id,
+                    Some(user_id),
+                    notification_type,
+                    PostType::Comment,
+                    comment.id,
+                    question_id,
+                ).await?;
+            }
+        }
+
+        txn.commit().await?;
+
+        self.events.publish(ActivityEvent::CommentCreated {
+            question_id,
+            comment_id: comment.id,
+            target_id,
+            target_type,
+        });
 
-        
         let user = users::Entity::find_by_id(user_id)
             .one(&self.db)
             .await?
             .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-        
-        let response = CommentResponse {
-            id: comment.id,
-            content: comment.content,
-            user_id: comment.user_id,
-            target_id: comment.target_id,
-            target_type: comment.target_type,
-            created_at: comment.created_at,
-            updated_at: comment.updated_at,
-            author: UserBrief {
-                id: user.id,
-                username: user.username,
-                display_name: user.display_name,
-                reputation: user.reputation,
-                avatar_url: user.avatar_url,
-            },
-        };
-        
-        Ok(response)
+        Ok(Self::to_response(comment, Self::to_brief(user), mentions, 0))
     }
 
     pub async fn get_comments(
         &self,
         target_id: i32,
-        target_type: &str,
+        target_type: TargetType,
     ) -> Result<Vec<CommentResponse>> {
         let comments = comments::Entity::find()
             .filter(
@@ -76,38 +131,76 @@ impl CommentRepository {
                     .add(comments::Column::TargetId.eq(target_id))
                     .add(comments::Column::TargetType.eq(target_type))
             )
-            .order_by_desc(comments::Column::CreatedAt)
+            .order_by_asc(comments::Column::CreatedAt)
             .all(&self.db)
             .await?;
+        let comments = Self::thread(comments);
 
-        let mut responses = Vec::new();
-        for comment in comments {
-            let user = users::Entity::find_by_id(comment.user_id)
-                .one(&self.db)
-                .await?
+        let author_ids: HashSet<i32> = comments.iter().map(|c| c.user_id).collect();
+        let authors: HashMap<i32, UserBrief> = users::Entity::find()
+            .filter(users::Column::Id.is_in(author_ids))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|user| (user.id, Self::to_brief(user)))
+            .collect();
+
+        let comment_ids: Vec<i32> = comments.iter().map(|c| c.id).collect();
+        let mut mentions = self.load_mentions(comment_ids.clone()).await?;
+        let scores = self.load_scores(comment_ids).await?;
+
+        let mut responses = Vec::with_capacity(comments.len());
+        for mut comment in comments {
+            let author = authors
+                .get(&comment.user_id)
+                .cloned()
                 .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+            let mut comment_mentions = mentions.remove(&comment.id).unwrap_or_default();
+            let mut score = scores.get(&comment.id).copied().unwrap_or(0);
 
-            responses.push(CommentResponse {
-                id: comment.id,
-                content: comment.content,
-                user_id: comment.user_id,
-                target_id: comment.target_id,
-                target_type: comment.target_type,
-                created_at: comment.created_at,
-                updated_at: comment.updated_at,
-                author: UserBrief {
-                    id: user.id,
-                    username: user.username,
-                    display_name: user.display_name,
-                    reputation: user.reputation,
-                    avatar_url: user.avatar_url,
-                },
-            });
+            if comment.hidden_at.is_some() {
+                comment.content.clear();
+                comment.rendered_html = Some(String::new());
+                comment_mentions.clear();
+                score = 0;
+            }
+
+            responses.push(Self::to_response(comment, author, comment_mentions, score));
         }
-        
+
         Ok(responses)
     }
 
+    fn thread(comments: Vec<comments::Model>) -> Vec<comments::Model> {
+        let mut replies: HashMap<Option<i32>, Vec<comments::Model>> = HashMap::new();
+        for comment in comments {
+            replies.entry(comment.parent_comment_id).or_default().push(comment);
+        }
+
+        let mut stack: Vec<comments::Model> = replies.remove(&None).unwrap_or_default();
+        let mut ordered = Vec::new();
+        while let Some(comment) = stack.pop() {
+            if let Some(children) = replies.remove(&Some(comment.id)) {
+                stack.extend(children.into_iter().rev());
+            }
+            ordered.push(comment);
+        }
+
+        let mut with_visible_replies = HashSet::new();
+        let mut threaded = Vec::with_capacity(ordered.len());
+        for comment in ordered.into_iter().rev() {
+            if comment.hidden_at.is_none() || with_visible_replies.contains(&comment.id) {
+                if let Some(parent_id) = comment.parent_comment_id {
+                    with_visible_replies.insert(parent_id);
+                }
+                threaded.push(comment);
+            }
+        }
+        threaded.reverse();
+
+        threaded
+    }
+
     pub async fn update_comment(
         &self,
         id: i32,
@@ -115,7 +208,7 @@ impl CommentRepository {
         content: String,
     ) -> Result<CommentResponse> {
         if content.trim().is_empty() {
-            return Err(AppError::Internal("Comment content cannot be empty".to_string()));
+            return Err(AppError::validation("content", "Comment content cannot be empty"));
         }
 
         let comment = comments::Entity::find_by_id(id)
@@ -124,38 +217,57 @@ impl CommentRepository {
             .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))?;
 
         if comment.user_id != user_id {
-            return Err(AppError::Internal("You can only update your own comments".to_string()));
+            return Err(AppError::Forbidden("You can only update your own comments".to_string()));
         }
 
         let now = chrono::Utc::now();
+        let txn = self.db.begin().await?;
+
         let comment = comments::ActiveModel {
             id: Set(id),
+            rendered_html: Set(Some(markdown::render(&content, &[]))),
+            render_version: Set(markdown::RENDER_VERSION),
             content: Set(content),
             updated_at: Set(now),
             ..Default::default()
-        }.update(&self.db).await?;
+        }.update(&txn).await?;
+
+        let mentions = self.sync_mentions(&txn, comment.id, user_id, &comment.content).await?;
+
+        txn.commit().await?;
+
+        if let Some(question_id) = self.find_question_id(comment.target_id, comment.target_type).await? {
+            self.events.publish(ActivityEvent::CommentEdited {
+                question_id,
+                comment_id: comment.id,
+                target_id: comment.target_id,
+                target_type: comment.target_type,
+            });
+        }
 
         let user = users::Entity::find_by_id(user_id)
             .one(&self.db)
             .await?
             .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-        Ok(CommentResponse {
-            id: comment.id,
-            content: comment.content,
-            user_id: comment.user_id,
-            target_id: comment.target_id,
-            target_type: comment.target_type,
-            created_at: comment.created_at,
-            updated_at: comment.updated_at,
-            author: UserBrief {
-                id: user.id,
-                username: user.username,
-                display_name: user.display_name,
-                reputation: user.reputation,
-                avatar_url: user.avatar_url,
-            },
-        })
+        let score = self.load_scores(vec![comment.id]).await?.get(&comment.id).copied().unwrap_or(0);
+
+        Ok(Self::to_response(comment, Self::to_brief(user), mentions, score))
+    }
+
+    pub async fn edit(txn: &DatabaseTransaction, comment_id: i32, content: String) -> Result<()> {
+        validation::check("content", validation::comment_body(&content))?;
+
+        comments::ActiveModel {
+            id: Set(comment_id),
+            rendered_html: Set(Some(markdown::render(&content, &[]))),
+            render_version: Set(markdown::RENDER_VERSION),
+            content: Set(content),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }.update(txn).await?;
+
+        Ok(())
     }
 
     pub async fn delete_comment(
@@ -169,13 +281,309 @@ impl CommentRepository {
             .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))?;
 
         if comment.user_id != user_id {
-            return Err(AppError::Internal("You can only delete your own comments".to_string()));
+            return Err(AppError::Forbidden("You can only delete your own comments".to_string()));
         }
 
         comments::Entity::delete_by_id(id)
             .exec(&self.db)
             .await?;
 
+        if let Some(question_id) = self.find_question_id(comment.target_id, comment.target_type).await? {
+            self.events.publish(ActivityEvent::CommentDeleted {
+                question_id,
+                comment_id: comment.id,
+                target_id: comment.target_id,
+                target_type: comment.target_type,
+            });
+        }
+
         Ok(())
     }
-}
\ No newline at end of file
+
+    pub async fn upvote_comment(&self, comment_id: i32, user_id: i32) -> Result<CommentResponse> {
+        let comment = self.find_visible_comment(comment_id).await?;
+
+        if comment.user_id == user_id {
+            return Err(AppError::Forbidden("You cannot upvote your own comment".to_string()));
+        }
+
+        let voter = users::Entity::find_by_id(user_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        if voter.verified_at.is_none() {
+            return Err(AppError::Forbidden("Verify your email address before voting".to_string()));
+        }
+
+        let existing = comment_votes::Entity::find()
+            .filter(comment_votes::Column::CommentId.eq(comment_id))
+            .filter(comment_votes::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await?;
+
+        if existing.is_none() {
+            comment_votes::ActiveModel {
+                comment_id: Set(comment_id),
+                user_id: Set(user_id),
+                created_at: Set(chrono::Utc::now()),
+                ..Default::default()
+            }.insert(&self.db).await?;
+        }
+
+        self.load_response(comment).await
+    }
+
+    pub async fn remove_comment_upvote(&self, comment_id: i32, user_id: i32) -> Result<CommentResponse> {
+        let comment = self.find_visible_comment(comment_id).await?;
+
+        comment_votes::Entity::delete_many()
+            .filter(comment_votes::Column::CommentId.eq(comment_id))
+            .filter(comment_votes::Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await?;
+
+        self.load_response(comment).await
+    }
+
+    async fn find_question_id(&self, target_id: i32, target_type: TargetType) -> Result<Option<i32>> {
+        let question_id = match target_type {
+            TargetType::Question => Some(target_id),
+            TargetType::Answer => answers::Entity::find_by_id(target_id)
+                .select_only()
+                .column(answers::Column::QuestionId)
+                .into_tuple::<i32>()
+                .one(&self.db)
+                .await?,
+        };
+
+        Ok(question_id)
+    }
+
+    async fn find_visible_comment(&self, comment_id: i32) -> Result<comments::Model> {
+        comments::Entity::find_by_id(comment_id)
+            .filter(comments::Column::HiddenAt.is_null())
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))
+    }
+
+    async fn load_response(&self, comment: comments::Model) -> Result<CommentResponse> {
+        let author = users::Entity::find_by_id(comment.user_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        let mentions = self.load_mentions(vec![comment.id]).await?.remove(&comment.id).unwrap_or_default();
+        let score = self.load_scores(vec![comment.id]).await?.get(&comment.id).copied().unwrap_or(0);
+
+        Ok(Self::to_response(comment, Self::to_brief(author), mentions, score))
+    }
+
+    async fn load_scores(&self, comment_ids: Vec<i32>) -> Result<HashMap<i32, i64>> {
+        if comment_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let scores = comment_votes::Entity::find()
+            .select_only()
+            .column(comment_votes::Column::CommentId)
+            .column_as(comment_votes::Column::Id.count(), "score")
+            .filter(comment_votes::Column::CommentId.is_in(comment_ids))
+            .group_by(comment_votes::Column::CommentId)
+            .into_tuple::<(i32, i64)>()
+            .all(&self.db)
+            .await?;
+
+        Ok(scores.into_iter().collect())
+    }
+
+    fn parse_mentions(content: &str) -> Vec<String> {
+        let mut usernames: Vec<String> = Vec::new();
+        let mut previous: Option<char> = None;
+        let mut chars = content.char_indices().peekable();
+
+        while let Some((index, c)) = chars.next() {
+            let starts_mention = c == '@'
+                && !previous.is_some_and(|p| p.is_alphanumeric() || p == '_' || p == '-' || p == '@');
+            previous = Some(c);
+
+            if !starts_mention {
+                continue;
+            }
+
+            let start = index + 1;
+            let mut end = start;
+            while let Some(&(next_index, next)) = chars.peek() {
+                if next.is_ascii_alphanumeric() || next == '_' || next == '-' {
+                    end = next_index + next.len_utf8();
+                    previous = Some(next);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+
+            let username = content[start..end].trim_end_matches('-');
+            if !username.is_empty() && !usernames.iter().any(|u| u == username) {
+                usernames.push(username.to_string());
+            }
+        }
+
+        usernames
+    }
+
+    async fn ensure_target_exists(&self, target_id: i32, target_type: TargetType) -> Result<(i32, i32)> {
+        let target = match target_type {
+            TargetType::Question => questions::Entity::find_by_id(target_id)
+                .one(&self.db)
+                .await?
+                .map(|question| (question.user_id, question.id, question.locked_at)),
+            TargetType::Answer => answers::Entity::find_by_id(target_id)
+                .one(&self.db)
+                .await?
+                .map(|answer| (answer.user_id, answer.question_id, answer.locked_at)),
+        };
+
+        match target {
+            None => Err(AppError::NotFound(format!("The {} being commented on does not exist", target_type.as_str()))),
+            Some((_, _, Some(_))) => Err(AppError::Forbidden(format!("This {} is locked and cannot receive new comments", target_type.as_str()))),
+            Some((author_id, question_id, None)) => Ok((author_id, question_id)),
+        }
+    }
+
+    async fn sync_mentions(
+        &self,
+        txn: &DatabaseTransaction,
+        comment_id: i32,
+        author_id: i32,
+        content: &str,
+    ) -> Result<Vec<UserBrief>> {
+        comment_mentions::Entity::delete_many()
+            .filter(comment_mentions::Column::CommentId.eq(comment_id))
+            .exec(txn)
+            .await?;
+
+        let usernames = Self::parse_mentions(content);
+        if usernames.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mentioned = users::Entity::find()
+            .filter(users::Column::Username.is_in(usernames))
+            .filter(users::Column::Id.ne(author_id))
+            .order_by_asc(users::Column::Username)
+            .all(txn)
+            .await?;
+
+        if !mentioned.is_empty() {
+            let now = chrono::Utc::now();
+            comment_mentions::Entity::insert_many(mentioned.iter().map(|user| comment_mentions::ActiveModel {
+                comment_id: Set(comment_id),
+                user_id: Set(user.id),
+                created_at: Set(now),
+                ..Default::default()
+            }))
+            .exec(txn)
+            .await?;
+        }
+
+        Ok(mentioned.into_iter().map(Self::to_brief).collect())
+    }
+
+    async fn load_mentions(&self, comment_ids: Vec<i32>) -> Result<HashMap<i32, Vec<UserBrief>>> {
+        let mut mentions: HashMap<i32, Vec<UserBrief>> = HashMap::new();
+        if comment_ids.is_empty() {
+            return Ok(mentions);
+        }
+
+        let rows = comment_mentions::Entity::find()
+            .filter(comment_mentions::Column::CommentId.is_in(comment_ids))
+            .find_also_related(users::Entity)
+            .order_by_asc(comment_mentions::Column::Id)
+            .all(&self.db)
+            .await?;
+
+        for (mention, user) in rows {
+            if let Some(user) = user {
+                mentions.entry(mention.comment_id).or_default().push(Self::to_brief(user));
+            }
+        }
+
+        Ok(mentions)
+    }
+
+    fn to_brief(user: users::Model) -> UserBrief {
+        UserBrief {
+            id: user.id,
+            username: user.username,
+            display_name: user.display_name,
+            reputation: user.reputation,
+            avatar_url: user.avatar_url,
+        }
+    }
+
+    fn to_response(comment: comments::Model, author: UserBrief, mentions: Vec<UserBrief>, score: i64) -> CommentResponse {
+        CommentResponse {
+            id: comment.id,
+            rendered_html: comment.rendered_html.unwrap_or_else(|| markdown::render(&comment.content, &[])),
+            content: comment.content,
+            user_id: comment.user_id,
+            target_id: comment.target_id,
+            target_type: comment.target_type,
+            parent_comment_id: comment.parent_comment_id,
+            depth: comment.depth,
+            hidden: comment.hidden_at.is_some(),
+            score,
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+            author,
+            mentions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(id: i32, parent_comment_id: Option<i32>, hidden: bool) -> comments::Model {
+        let now = chrono::Utc::now();
+
+        comments::Model {
+            id,
+            content: format!("comment {id}"),
+            user_id: 1,
+            created_at: now,
+            updated_at: now,
+            target_id: 1,
+            target_type: TargetType::Question,
+            parent_comment_id,
+            depth: 0,
+            hidden_at: hidden.then_some(now),
+            rendered_html: None,
+            render_version: 0,
+        }
+    }
+
+    #[test]
+    fn thread_keeps_replies_of_hidden_parents() {
+        let threaded = CommentRepository::thread(vec![
+            comment(1, None, true),
+            comment(2, Some(1), false),
+            comment(3, None, true),
+            comment(4, None, true),
+            comment(5, Some(4), true),
+            comment(6, Some(5), false),
+        ]);
+
+        let ids: Vec<i32> = threaded.iter().map(|c| c.id).collect();
+        let position = |id: i32| ids.iter().position(|&other| other == id).unwrap();
+
+        assert_eq!(ids.len(), 5);
+        assert!(!ids.contains(&3));
+        assert!(position(1) < position(2));
+        assert!(position(4) < position(5) && position(5) < position(6));
+        assert!(threaded.iter().filter(|c| [1, 4, 5].contains(&c.id)).all(|c| c.hidden_at.is_some()));
+    }
+}
@@ -0,0 +1,612 @@
+use sea_orm::*;
+use sea_orm::sea_query::{Alias, Expr, Query};
+use std::collections::{HashMap, HashSet};
+use crate::entities::{answers, bookmarks, comments, flags, post_links, questions, review_tasks, users, votes};
+use crate::entities::sea_orm_active_enums::{
+    FlagReason, FlagStatus, ModerationAction, PostType, TargetType,
+};
+use crate::models::flag_models::{
+    FlagReasonCount, FlagResponse, FlaggedPostResponse, ResolveFlagRequest, UserBrief,
+};
+use crate::repository::answer_repository::AnswerRepository;
+use crate::repository::comment_repository::CommentRepository;
+use crate::repository::question_repository::QuestionRepository;
+use crate::error::{AppError, Result};
+
+const PREVIEW_LENGTH: usize = 200;
+
+pub struct FlagRepository {
+    db: DatabaseConnection,
+}
+
+struct PostSummary {
+    title: String,
+    preview: String,
+    author_id: i32,
+    is_hidden: bool,
+    is_locked: bool,
+}
+
+impl FlagRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create_flag(
+        &self,
+        user_id: i32,
+        post_type: PostType,
+        post_id: i32,
+        reason: FlagReason,
+        text: Option<String>,
+        comment_hide_threshold: u64,
+    ) -> Result<FlagResponse> {
+        let text = text.map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
+        if reason == FlagReason::Other && text.is_none() {
+            return Err(AppError::validation("text", "Describe the problem when flagging for another reason"));
+        }
+
+        let post = Self::load_post(&self.db, post_type, post_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("The flagged post does not exist".to_string()))?;
+
+        if post.author_id == user_id {
+            return Err(AppError::Forbidden("You cannot flag your own post".to_string()));
+        }
+
+        let txn = self.db.begin().await?;
+
+        let already_flagged = flags::Entity::find()
+            .filter(flags::Column::PostType.eq(post_type))
+            .filter(flags::Column::PostId.eq(post_id))
+            .filter(flags::Column::UserId.eq(user_id))
+            .count(&txn)
+            .await? > 0;
+
+        if already_flagged {
+            return Err(AppError::Conflict("You have already flagged this post".to_string()));
+        }
+
+        let flag = flags::ActiveModel {
+            post_type: Set(post_type),
+            post_id: Set(post_id),
+            user_id: Set(user_id),
+            reason: Set(reason),
+            text: Set(text),
+            status: Set(FlagStatus::Pending),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }.insert(&txn).await?;
+
+        if post_type == PostType::Comment && !post.is_hidden {
+            let weight = Self::pending_weight(&txn, post_type, post_id).await?;
+
+            if weight >= comment_hide_threshold as f64 {
+                comments::ActiveModel {
+                    id: Set(post_id),
+                    hidden_at: Set(Some(chrono::Utc::now())),
+                    ..Default::default()
+                }.update(&txn).await?;
+            }
+        }
+
+        txn.commit().await?;
+
+        self.to_response(flag).await
+    }
+
+    pub async fn get_queue(&self, page: u64, per_page: u64) -> Result<Vec<FlaggedPostResponse>> {
+        let page = page.max(1);
+        let per_page = per_page.clamp(1, 100);
+
+        let weights = Alias::new("flagger_weights");
+        let weight = Expr::col((weights.clone(), Alias::new("weight"))).sum();
+        let flagger_weights = Query::select()
+            .column(flags::Column::UserId)
+            .expr_as(
+                Expr::val(2.0)
+                    .mul(Expr::expr(Expr::case(flags::Column::Status.eq(FlagStatus::Helpful), 1).finally(0)).sum().add(1.0))
+                    .div(Expr::expr(Expr::case(flags::Column::Status.ne(FlagStatus::Pending), 1).finally(0)).sum().add(2.0)),
+                Alias::new("weight"),
+            )
+            .from(flags::Entity)
+            .group_by_col(flags::Column::UserId)
+            .to_owned();
+
+        let mut pending = flags::Entity::find()
+            .select_only()
+            .column(flags::Column::PostType)
+            .column(flags::Column::PostId)
+            .column_as(weight.clone(), "pending_weight")
+            .filter(flags::Column::Status.eq(FlagStatus::Pending))
+            .group_by(flags::Column::PostType)
+            .group_by(flags::Column::PostId)
+            .order_by(weight, Order::Desc)
+            .order_by(Expr::col((flags::Entity, flags::Column::CreatedAt)).min(), Order::Asc)
+            .offset((page - 1) * per_page)
+            .limit(per_page);
+        QueryTrait::query(&mut pending).join_subquery(
+            JoinType::InnerJoin,
+            flagger_weights,
+            weights.clone(),
+            Expr::col((weights, flags::Column::UserId)).equals((flags::Entity, flags::Column::UserId)),
+        );
+        let pending = pending.into_tuple::<(PostType, i32, f64)>().all(&self.db).await?;
+
+        if pending.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let keys: Vec<(PostType, i32)> = pending.iter().map(|(post_type, post_id, _)| (*post_type, *post_id)).collect();
+        let post_ids: HashSet<i32> = keys.iter().map(|(_, id)| *id).collect();
+        let history = flags::Entity::find()
+            .filter(flags::Column::PostId.is_in(post_ids))
+            .order_by_asc(flags::Column::CreatedAt)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .filter(|f| keys.contains(&(f.post_type, f.post_id)))
+            .collect::<Vec<_>>();
+
+        let mut histories: HashMap<(PostType, i32), Vec<FlagResponse>> = HashMap::new();
+        for response in self.to_responses(history).await? {
+            histories.entry((response.post_type, response.post_id)).or_default().push(response);
+        }
+
+        let mut posts = Self::load_posts(&self.db, &keys).await?;
+        let author_ids: HashSet<i32> = posts.values().map(|post| post.author_id).collect();
+        let authors: HashMap<i32, UserBrief> = users::Entity::find()
+            .filter(users::Column::Id.is_in(author_ids))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|user| (user.id, Self::to_brief(user)))
+            .collect();
+
+        let mut responses = Vec::new();
+        for (post_type, post_id, pending_weight) in pending {
+            let history = histories.remove(&(post_type, post_id)).unwrap_or_default();
+            let post = posts.remove(&(post_type, post_id));
+            let author = post.as_ref().and_then(|post| authors.get(&post.author_id).cloned());
+
+            let mut reasons: Vec<FlagReasonCount> = Vec::new();
+            for flag in history.iter().filter(|f| f.status == FlagStatus::Pending) {
+                match reasons.iter_mut().find(|r| r.reason == flag.reason) {
+                    Some(entry) => entry.count += 1,
+                    None => reasons.push(FlagReasonCount { reason: flag.reason, count: 1 }),
+                }
+            }
+            reasons.sort_by_key(|r| std::cmp::Reverse(r.count));
+
+            let (title, preview, is_hidden, is_locked) = match post {
+                Some(post) => (post.title, post.preview, post.is_hidden, post.is_locked),
+                None => ("[deleted]".to_string(), String::new(), false, false),
+            };
+
+            responses.push(FlaggedPostResponse {
+                post_type,
+                post_id,
+                title,
+                preview,
+                author,
+                is_hidden,
+                is_locked,
+                pending_count: reasons.iter().map(|r| r.count).sum(),
+                total_count: history.len() as u64,
+                pending_weight,
+                reasons,
+                history,
+            });
+        }
+
+        Ok(responses)
+    }
+
+    pub async fn resolve_flag(
+        &self,
+        moderator_id: i32,
+        flag_id: i32,
+        request: ResolveFlagRequest,
+    ) -> Result<FlagResponse> {
+        if request.status == FlagStatus::Pending {
+            return Err(AppError::validation("status", "Flags can only be resolved as helpful or declined"));
+        }
+
+        if request.status == FlagStatus::Declined && request.action.is_some() {
+            return Err(AppError::validation("action", "Actions can only be taken when a flag is marked helpful"));
+        }
+
+        let flag = flags::Entity::find_by_id(flag_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Flag not found".to_string()))?;
+
+        if flag.status != FlagStatus::Pending {
+            return Err(AppError::Conflict("This flag has already been resolved".to_string()));
+        }
+
+        let now = chrono::Utc::now();
+        let txn = self.db.begin().await?;
+
+        match request.action {
+            Some(ModerationAction::Delete) => {
+                Self::delete_post(&txn, flag.post_type, flag.post_id).await?;
+            }
+            Some(ModerationAction::Lock) => {
+                Self::lock_post(&txn, flag.post_type, flag.post_id).await?;
+            }
+            Some(ModerationAction::Edit) => {
+                let content = request
+                    .content
+                    .clone()
+                    .ok_or_else(|| AppError::validation("content", "Provide the edited content"))?;
+                match flag.post_type {
+                    PostType::Question => {
+                        QuestionRepository::edit(&txn, moderator_id, flag.post_id, request.title.clone(), content).await?;
+                    }
+                    _ if request.title.is_some() => {
+                        return Err(AppError::validation("title", "Only questions have a title"));
+                    }
+                    PostType::Answer => AnswerRepository::edit(&txn, moderator_id, flag.post_id, content).await?,
+                    PostType::Comment => CommentRepository::edit(&txn, flag.post_id, content).await?,
+                }
+            }
+            None => {}
+        }
+
+        let resolved = flags::ActiveModel {
+            id: Set(flag.id),
+            status: Set(request.status),
+            resolved_by: Set(Some(moderator_id)),
+            resolved_at: Set(Some(now)),
+            resolution_action: Set(request.action),
+            resolution_note: Set(request.note.clone()),
+            ..Default::default()
+        }.update(&txn).await?;
+
+        if request.action == Some(ModerationAction::Delete) {
+            flags::Entity::update_many()
+                .filter(flags::Column::PostType.eq(flag.post_type))
+                .filter(flags::Column::PostId.eq(flag.post_id))
+                .filter(flags::Column::Status.eq(FlagStatus::Pending))
+                .set(flags::ActiveModel {
+                    status: Set(FlagStatus::Helpful),
+                    resolved_by: Set(Some(moderator_id)),
+                    resolved_at: Set(Some(now)),
+                    resolution_action: Set(Some(ModerationAction::Delete)),
+                    ..Default::default()
+                })
+                .exec(&txn)
+                .await?;
+        } else if flag.post_type == PostType::Comment
+            && (request.status == FlagStatus::Declined || request.action == Some(ModerationAction::Edit))
+        {
+            let remaining = flags::Entity::find()
+                .filter(flags::Column::PostType.eq(PostType::Comment))
+                .filter(flags::Column::PostId.eq(flag.post_id))
+                .filter(flags::Column::Status.eq(FlagStatus::Pending))
+                .count(&txn)
+                .await?;
+
+            if remaining == 0 {
+                comments::Entity::update_many()
+                    .filter(comments::Column::Id.eq(flag.post_id))
+                    .set(comments::ActiveModel {
+                        hidden_at: Set(None),
+                        ..Default::default()
+                    })
+                    .exec(&txn)
+                    .await?;
+            }
+        }
+
+        txn.commit().await?;
+
+        self.to_response(resolved).await
+    }
+
+    async fn to_response(&self, flag: flags::Model) -> Result<FlagResponse> {
+        self.to_responses(vec![flag])
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::Internal("Failed to load flag".to_string()))
+    }
+
+    async fn to_responses(&self, flags: Vec<flags::Model>) -> Result<Vec<FlagResponse>> {
+        let flagger_ids: HashSet<i32> = flags.iter().map(|f| f.user_id).collect();
+
+        let flaggers: HashMap<i32, UserBrief> = users::Entity::find()
+            .filter(users::Column::Id.is_in(flagger_ids.clone()))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|user| (user.id, Self::to_brief(user)))
+            .collect();
+
+        let weights = Self::flagger_weights(&self.db, flagger_ids.into_iter().collect()).await?;
+
+        flags
+            .into_iter()
+            .map(|flag| {
+                let flagger = flaggers
+                    .get(&flag.user_id)
+                    .cloned()
+                    .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+                Ok(FlagResponse {
+                    id: flag.id,
+                    post_type: flag.post_type,
+                    post_id: flag.post_id,
+                    reason: flag.reason,
+                    text: flag.text,
+                    status: flag.status,
+                    flagger,
+                    weight: weights.get(&flag.user_id).copied().unwrap_or(1.0),
+                    resolved_by: flag.resolved_by,
+                    resolved_at: flag.resolved_at,
+                    resolution_action: flag.resolution_action,
+                    resolution_note: flag.resolution_note,
+                    created_at: flag.created_at,
+                })
+            })
+            .collect()
+    }
+
+    async fn flagger_weights<C: ConnectionTrait>(conn: &C, user_ids: Vec<i32>) -> Result<HashMap<i32, f64>> {
+        if user_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows = flags::Entity::find()
+            .select_only()
+            .column(flags::Column::UserId)
+            .column(flags::Column::Status)
+            .column_as(flags::Column::Id.count(), "count")
+            .filter(flags::Column::UserId.is_in(user_ids))
+            .filter(flags::Column::Status.ne(FlagStatus::Pending))
+            .group_by(flags::Column::UserId)
+            .group_by(flags::Column::Status)
+            .into_tuple::<(i32, FlagStatus, i64)>()
+            .all(conn)
+            .await?;
+
+        let mut history: HashMap<i32, (i64, i64)> = HashMap::new();
+        for (user_id, status, count) in rows {
+            let entry = history.entry(user_id).or_default();
+            match status {
+                FlagStatus::Helpful => entry.0 += count,
+                FlagStatus::Declined => entry.1 += count,
+                FlagStatus::Pending => {}
+            }
+        }
+
+        Ok(history
+            .into_iter()
+            .map(|(user_id, (helpful, declined))| {
+                (user_id, 2.0 * (helpful as f64 + 1.0) / ((helpful + declined) as f64 + 2.0))
+            })
+            .collect())
+    }
+
+    async fn pending_weight<C: ConnectionTrait>(conn: &C, post_type: PostType, post_id: i32) -> Result<f64> {
+        let flagger_ids: Vec<i32> = flags::Entity::find()
+            .select_only()
+            .column(flags::Column::UserId)
+            .filter(flags::Column::PostType.eq(post_type))
+            .filter(flags::Column::PostId.eq(post_id))
+            .filter(flags::Column::Status.eq(FlagStatus::Pending))
+            .into_tuple::<i32>()
+            .all(conn)
+            .await?;
+
+        let weights = Self::flagger_weights(conn, flagger_ids.clone()).await?;
+
+        Ok(flagger_ids
+            .iter()
+            .map(|id| weights.get(id).copied().unwrap_or(1.0))
+            .sum())
+    }
+
+    async fn load_post<C: ConnectionTrait>(conn: &C, post_type: PostType, post_id: i32) -> Result<Option<PostSummary>> {
+        Ok(Self::load_posts(conn, &[(post_type, post_id)]).await?.remove(&(post_type, post_id)))
+    }
+
+    async fn load_posts<C: ConnectionTrait>(
+        conn: &C,
+        keys: &[(PostType, i32)],
+    ) -> Result<HashMap<(PostType, i32), PostSummary>> {
+        let ids = |post_type: PostType| -> Vec<i32> {
+            keys.iter().filter(|(t, _)| *t == post_type).map(|(_, id)| *id).collect()
+        };
+        let mut summaries = HashMap::new();
+
+        let answers = answers::Entity::find()
+            .filter(answers::Column::Id.is_in(ids(PostType::Answer)))
+            .all(conn)
+            .await?;
+        let question_ids: HashSet<i32> = ids(PostType::Question)
+            .into_iter()
+            .chain(answers.iter().map(|answer| answer.question_id))
+            .collect();
+        let questions: HashMap<i32, questions::Model> = questions::Entity::find()
+            .filter(questions::Column::Id.is_in(question_ids))
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|question| (question.id, question))
+            .collect();
+
+        for id in ids(PostType::Question) {
+            if let Some(question) = questions.get(&id) {
+                summaries.insert((PostType::Question, id), PostSummary {
+                    title: question.title.clone(),
+                    preview: Self::truncate_content(&question.content),
+                    author_id: question.user_id,
+                    is_hidden: false,
+                    is_locked: question.locked_at.is_some(),
+                });
+            }
+        }
+
+        for answer in answers {
+            let question_title = questions
+                .get(&answer.question_id)
+                .map(|q| q.title.as_str())
+                .unwrap_or_default();
+
+            summaries.insert((PostType::Answer, answer.id), PostSummary {
+                title: format!("Answer to: {}", question_title),
+                preview: Self::truncate_content(&answer.content),
+                author_id: answer.user_id,
+                is_hidden: false,
+                is_locked: answer.locked_at.is_some(),
+            });
+        }
+
+        let comments = comments::Entity::find()
+            .filter(comments::Column::Id.is_in(ids(PostType::Comment)))
+            .all(conn)
+            .await?;
+        for comment in comments {
+            summaries.insert((PostType::Comment, comment.id), PostSummary {
+                title: format!("Comment on {} {}", comment.target_type.as_str(), comment.target_id),
+                preview: Self::truncate_content(&comment.content),
+                author_id: comment.user_id,
+                is_hidden: comment.hidden_at.is_some(),
+                is_locked: false,
+            });
+        }
+
+        Ok(summaries)
+    }
+
+    async fn delete_post(txn: &DatabaseTransaction, post_type: PostType, post_id: i32) -> Result<()> {
+        match post_type {
+            PostType::Question => {
+                let answer_ids: Vec<i32> = answers::Entity::find()
+                    .select_only()
+                    .column(answers::Column::Id)
+                    .filter(answers::Column::QuestionId.eq(post_id))
+                    .into_tuple::<i32>()
+                    .all(txn)
+                    .await?;
+
+                Self::delete_attached_content(txn, TargetType::Answer, answer_ids).await?;
+                Self::delete_attached_content(txn, TargetType::Question, vec![post_id]).await?;
+
+                let result = questions::Entity::delete_by_id(post_id).exec(txn).await?;
+                if result.rows_affected == 0 {
+                    return Err(AppError::NotFound("Question not found".to_string()));
+                }
+            }
+            PostType::Answer => {
+                let answer = answers::Entity::find_by_id(post_id)
+                    .one(txn)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound("Answer not found".to_string()))?;
+
+                Self::delete_attached_content(txn, TargetType::Answer, vec![post_id]).await?;
+                answers::Entity::delete_by_id(post_id).exec(txn).await?;
+
+                if answer.is_accepted != 0 {
+                    questions::ActiveModel {
+                        id: Set(answer.question_id),
+                        is_answered: Set(0),
+                        ..Default::default()
+                    }.update(txn).await?;
+                }
+            }
+            PostType::Comment => {
+                let result = comments::Entity::delete_by_id(post_id).exec(txn).await?;
+                if result.rows_affected == 0 {
+                    return Err(AppError::NotFound("Comment not found".to_string()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete_attached_content(txn: &DatabaseTransaction, target_type: TargetType, target_ids: Vec<i32>) -> Result<()> {
+        if target_ids.is_empty() {
+            return Ok(());
+        }
+
+        comments::Entity::delete_many()
+            .filter(comments::Column::TargetType.eq(target_type))
+            .filter(comments::Column::TargetId.is_in(target_ids.clone()))
+            .exec(txn)
+            .await?;
+
+        votes::Entity::delete_many()
+            .filter(votes::Column::TargetType.eq(target_type))
+            .filter(votes::Column::TargetId.is_in(target_ids.clone()))
+            .exec(txn)
+            .await?;
+
+        bookmarks::Entity::delete_many()
+            .filter(bookmarks::Column::TargetType.eq(target_type))
+            .filter(bookmarks::Column::TargetId.is_in(target_ids.clone()))
+            .exec(txn)
+            .await?;
+
+        review_tasks::Entity::delete_many()
+            .filter(review_tasks::Column::PostType.eq(PostType::from(target_type)))
+            .filter(review_tasks::Column::PostId.is_in(target_ids.clone()))
+            .exec(txn)
+            .await?;
+
+        post_links::Entity::delete_many()
+            .filter(post_links::Column::PostType.eq(PostType::from(target_type)))
+            .filter(post_links::Column::PostId.is_in(target_ids))
+            .exec(txn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn lock_post(txn: &DatabaseTransaction, post_type: PostType, post_id: i32) -> Result<()> {
+        let now = chrono::Utc::now();
+
+        match post_type {
+            PostType::Question => {
+                questions::ActiveModel {
+                    id: Set(post_id),
+                    locked_at: Set(Some(now)),
+                    ..Default::default()
+                }.update(txn).await?;
+            }
+            PostType::Answer => {
+                answers::ActiveModel {
+                    id: Set(post_id),
+                    locked_at: Set(Some(now)),
+                    ..Default::default()
+                }.update(txn).await?;
+            }
+            PostType::Comment => {
+                return Err(AppError::validation("action", "Comments cannot be locked"));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn to_brief(user: users::Model) -> UserBrief {
+        UserBrief {
+            id: user.id,
+            username: user.username,
+            display_name: user.display_name,
+            reputation: user.reputation,
+            avatar_url: user.avatar_url,
+        }
+    }
+
+    fn truncate_content(content: &str) -> String {
+        if content.chars().count() <= PREVIEW_LENGTH {
+            return content.to_string();
+        }
+
+        let truncated = content.chars().take(PREVIEW_LENGTH).collect::<String>();
+        format!("{}...", truncated)
+    }
+}
@@ -0,0 +1,157 @@
+use sea_orm::*;
+use sea_orm::sea_query::OnConflict;
+use crate::entities::{question_follows, questions, tag_preferences, tags, users};
+use crate::entities::sea_orm_active_enums::TagPreference;
+use crate::models::follow_models::{FollowedQuestionResponse, TagBrief, TagPreferencesResponse};
+use crate::error::{AppError, Result};
+
+pub struct FollowRepository {
+    db: DatabaseConnection,
+}
+
+impl FollowRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn follow<C: ConnectionTrait>(conn: &C, user_id: i32, question_id: i32) -> Result<()> {
+        question_follows::Entity::insert(question_follows::ActiveModel {
+            user_id: Set(user_id),
+            question_id: Set(question_id),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::columns([question_follows::Column::UserId, question_follows::Column::QuestionId])
+                .do_nothing()
+                .to_owned(),
+        )
+        .exec_without_returning(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn follow_question(&self, user_id: i32, question_id: i32) -> Result<Vec<FollowedQuestionResponse>> {
+        questions::Entity::find_by_id(question_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
+
+        Self::follow(&self.db, user_id, question_id).await?;
+
+        self.list_followed_questions(user_id).await
+    }
+
+    pub async fn unfollow_question(&self, user_id: i32, question_id: i32) -> Result<Vec<FollowedQuestionResponse>> {
+        question_follows::Entity::delete_many()
+            .filter(question_follows::Column::UserId.eq(user_id))
+            .filter(question_follows::Column::QuestionId.eq(question_id))
+            .exec(&self.db)
+            .await?;
+
+        self.list_followed_questions(user_id).await
+    }
+
+    pub async fn list_followed_questions(&self, user_id: i32) -> Result<Vec<FollowedQuestionResponse>> {
+        let follows = question_follows::Entity::find()
+            .filter(question_follows::Column::UserId.eq(user_id))
+            .find_also_related(questions::Entity)
+            .order_by_desc(question_follows::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+
+        Ok(follows
+            .into_iter()
+            .filter_map(|(follow, question)| {
+                question.map(|question| FollowedQuestionResponse {
+                    question_id: question.id,
+                    title: question.title,
+                    is_answered: question.is_answered != 0,
+                    followed_at: follow.created_at,
+                })
+            })
+            .collect())
+    }
+
+    pub async fn set_tag_preference(
+        &self,
+        user_id: i32,
+        tag_id: i32,
+        preference: TagPreference,
+    ) -> Result<TagPreferencesResponse> {
+        tags::Entity::find_by_id(tag_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Tag not found".to_string()))?;
+
+        tag_preferences::Entity::insert(tag_preferences::ActiveModel {
+            user_id: Set(user_id),
+            tag_id: Set(tag_id),
+            preference: Set(preference),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::columns([tag_preferences::Column::UserId, tag_preferences::Column::TagId])
+                .update_columns([tag_preferences::Column::Preference, tag_preferences::Column::CreatedAt])
+                .to_owned(),
+        )
+        .exec_without_returning(&self.db)
+        .await?;
+
+        self.get_tag_preferences(user_id).await
+    }
+
+    pub async fn clear_tag_preference(&self, user_id: i32, tag_id: i32) -> Result<TagPreferencesResponse> {
+        tag_preferences::Entity::delete_many()
+            .filter(tag_preferences::Column::UserId.eq(user_id))
+            .filter(tag_preferences::Column::TagId.eq(tag_id))
+            .exec(&self.db)
+            .await?;
+
+        self.get_tag_preferences(user_id).await
+    }
+
+    pub async fn set_hide_ignored_tags(&self, user_id: i32, hide_ignored_tags: bool) -> Result<TagPreferencesResponse> {
+        users::ActiveModel {
+            id: Set(user_id),
+            hide_ignored_tags: Set(hide_ignored_tags),
+            ..Default::default()
+        }.update(&self.db).await?;
+
+        self.get_tag_preferences(user_id).await
+    }
+
+    pub async fn get_tag_preferences(&self, user_id: i32) -> Result<TagPreferencesResponse> {
+        let user = users::Entity::find_by_id(user_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        let preferences = tag_preferences::Entity::find()
+            .filter(tag_preferences::Column::UserId.eq(user_id))
+            .find_also_related(tags::Entity)
+            .order_by_asc(tags::Column::Name)
+            .all(&self.db)
+            .await?;
+
+        let mut response = TagPreferencesResponse {
+            watched: Vec::new(),
+            ignored: Vec::new(),
+            hide_ignored_tags: user.hide_ignored_tags,
+        };
+
+        for (preference, tag) in preferences {
+            let Some(tag) = tag else { continue };
+            let brief = TagBrief { id: tag.id, name: tag.name };
+
+            match preference.preference {
+                TagPreference::Watched => response.watched.push(brief),
+                TagPreference::Ignored => response.ignored.push(brief),
+            }
+        }
+
+        Ok(response)
+    }
+}
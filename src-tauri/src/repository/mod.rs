@@ -4,4 +4,13 @@ pub mod question_repository;
 pub mod tag_repository;
 pub mod vote_repository;
 pub mod comment_repository;
-pub mod bookmark_repository;
\ No newline at end of file
+pub mod bookmark_repository;
+pub mod two_factor_repository;
+pub mod personal_access_token_repository;
+pub mod flag_repository;
+pub mod review_repository;
+pub mod notification_repository;
+pub mod follow_repository;
+pub mod tag_synonym_repository;
+pub mod search_repository;
+pub mod render_repository;
\ No newline at end of file
@@ -0,0 +1,270 @@
+use sea_orm::*;
+use sea_orm::sea_query::OnConflict;
+use std::collections::{HashMap, HashSet};
+use crate::entities::{notification_preferences, notifications, question_follows, users};
+use crate::entities::sea_orm_active_enums::{NotificationType, PostType};
+use crate::models::notification_models::{
+    NotificationPreference, NotificationResponse, UnreadCountResponse, UserBrief,
+};
+use crate::error::Result;
+
+pub struct NotificationRepository {
+    db: DatabaseConnection,
+}
+
+impl NotificationRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn notify<C: ConnectionTrait>(
+        conn: &C,
+        user_id: i32,
+        actor_id: Option<i32>,
+        notification_type: NotificationType,
+        post_type: PostType,
+        post_id: i32,
+        question_id: i32,
+    ) -> Result<()> {
+        if actor_id == Some(user_id) || !Self::is_enabled(conn, user_id, notification_type).await? {
+            return Ok(());
+        }
+
+        let now = chrono::Utc::now();
+        notifications::ActiveModel {
+            user_id: Set(user_id),
+            actor_id: Set(actor_id),
+            notification_type: Set(notification_type),
+            post_type: Set(post_type),
+            post_id: Set(post_id),
+            question_id: Set(question_id),
+            reputation_change: Set(0),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        }.insert(conn).await?;
+
+        Ok(())
+    }
+
+    pub async fn notify_followers<C: ConnectionTrait>(
+        conn: &C,
+        question_id: i32,
+        actor_id: Option<i32>,
+        notification_type: NotificationType,
+        post_type: PostType,
+        post_id: i32,
+        skip: &[i32],
+    ) -> Result<()> {
+        let followers: Vec<i32> = question_follows::Entity::find()
+            .select_only()
+            .column(question_follows::Column::UserId)
+            .filter(question_follows::Column::QuestionId.eq(question_id))
+            .into_tuple::<i32>()
+            .all(conn)
+            .await?;
+
+        for user_id in followers.into_iter().filter(|id| !skip.contains(id)) {
+            Self::notify(conn, user_id, actor_id, notification_type, post_type, post_id, question_id).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn record_reputation<C: ConnectionTrait>(
+        conn: &C,
+        user_id: i32,
+        post_type: PostType,
+        post_id: i32,
+        question_id: i32,
+        reputation_change: i32,
+    ) -> Result<()> {
+        if reputation_change == 0 || !Self::is_enabled(conn, user_id, NotificationType::Reputation).await? {
+            return Ok(());
+        }
+
+        let now = chrono::Utc::now();
+        let pending = notifications::Entity::find()
+            .filter(notifications::Column::UserId.eq(user_id))
+            .filter(notifications::Column::NotificationType.eq(NotificationType::Reputation))
+            .filter(notifications::Column::PostType.eq(post_type))
+            .filter(notifications::Column::PostId.eq(post_id))
+            .filter(notifications::Column::ReadAt.is_null())
+            .one(conn)
+            .await?;
+
+        match pending {
+            Some(notification) => {
+                let total = notification.reputation_change + reputation_change;
+                if total == 0 {
+                    notifications::Entity::delete_by_id(notification.id).exec(conn).await?;
+                } else {
+                    notifications::ActiveModel {
+                        id: Set(notification.id),
+                        reputation_change: Set(total),
+                        updated_at: Set(now),
+                        ..Default::default()
+                    }.update(conn).await?;
+                }
+            }
+            None => {
+                notifications::ActiveModel {
+                    user_id: Set(user_id),
+                    notification_type: Set(NotificationType::Reputation),
+                    post_type: Set(post_type),
+                    post_id: Set(post_id),
+                    question_id: Set(question_id),
+                    reputation_change: Set(reputation_change),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                }.insert(conn).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn list_notifications(
+        &self,
+        user_id: i32,
+        page: u64,
+        per_page: u64,
+        unread_only: bool,
+    ) -> Result<Vec<NotificationResponse>> {
+        let mut query = notifications::Entity::find()
+            .filter(notifications::Column::UserId.eq(user_id));
+
+        if unread_only {
+            query = query.filter(notifications::Column::ReadAt.is_null());
+        }
+
+        let notifications = query
+            .order_by_desc(notifications::Column::UpdatedAt)
+            .order_by_desc(notifications::Column::Id)
+            .paginate(&self.db, per_page.clamp(1, 100))
+            .fetch_page(page.max(1) - 1)
+            .await?;
+
+        let actor_ids: HashSet<i32> = notifications.iter().filter_map(|n| n.actor_id).collect();
+        let actors: HashMap<i32, UserBrief> = users::Entity::find()
+            .filter(users::Column::Id.is_in(actor_ids))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|user| {
+                (user.id, UserBrief {
+                    id: user.id,
+                    username: user.username,
+                    display_name: user.display_name,
+                    reputation: user.reputation,
+                    avatar_url: user.avatar_url,
+                })
+            })
+            .collect();
+
+        Ok(notifications
+            .into_iter()
+            .map(|notification| NotificationResponse {
+                id: notification.id,
+                notification_type: notification.notification_type,
+                actor: notification.actor_id.and_then(|id| actors.get(&id).cloned()),
+                post_type: notification.post_type,
+                post_id: notification.post_id,
+                question_id: notification.question_id,
+                reputation_change: notification.reputation_change,
+                is_read: notification.read_at.is_some(),
+                created_at: notification.created_at,
+                updated_at: notification.updated_at,
+            })
+            .collect())
+    }
+
+    pub async fn mark_read(&self, user_id: i32, ids: Option<Vec<i32>>) -> Result<UnreadCountResponse> {
+        let mut update = notifications::Entity::update_many()
+            .filter(notifications::Column::UserId.eq(user_id))
+            .filter(notifications::Column::ReadAt.is_null());
+
+        if let Some(ids) = ids {
+            update = update.filter(notifications::Column::Id.is_in(ids));
+        }
+
+        update
+            .set(notifications::ActiveModel {
+                read_at: Set(Some(chrono::Utc::now())),
+                ..Default::default()
+            })
+            .exec(&self.db)
+            .await?;
+
+        self.get_unread_count(user_id).await
+    }
+
+    pub async fn get_unread_count(&self, user_id: i32) -> Result<UnreadCountResponse> {
+        let unread = notifications::Entity::find()
+            .filter(notifications::Column::UserId.eq(user_id))
+            .filter(notifications::Column::ReadAt.is_null())
+            .count(&self.db)
+            .await?;
+
+        Ok(UnreadCountResponse { unread })
+    }
+
+    pub async fn get_preferences(&self, user_id: i32) -> Result<Vec<NotificationPreference>> {
+        let stored: HashMap<NotificationType, bool> = notification_preferences::Entity::find()
+            .filter(notification_preferences::Column::UserId.eq(user_id))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|preference| (preference.notification_type, preference.enabled))
+            .collect();
+
+        Ok(NotificationType::iter()
+            .map(|notification_type| NotificationPreference {
+                notification_type,
+                enabled: stored.get(&notification_type).copied().unwrap_or(true),
+            })
+            .collect())
+    }
+
+    pub async fn update_preferences(
+        &self,
+        user_id: i32,
+        preferences: Vec<NotificationPreference>,
+    ) -> Result<Vec<NotificationPreference>> {
+        let txn = self.db.begin().await?;
+
+        for preference in preferences {
+            notification_preferences::Entity::insert(notification_preferences::ActiveModel {
+                user_id: Set(user_id),
+                notification_type: Set(preference.notification_type),
+                enabled: Set(preference.enabled),
+                ..Default::default()
+            })
+            .on_conflict(
+                OnConflict::columns([
+                    notification_preferences::Column::UserId,
+                    notification_preferences::Column::NotificationType,
+                ])
+                .update_column(notification_preferences::Column::Enabled)
+                .to_owned(),
+            )
+            .exec(&txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+
+        self.get_preferences(user_id).await
+    }
+
+    async fn is_enabled<C: ConnectionTrait>(conn: &C, user_id: i32, notification_type: NotificationType) -> Result<bool> {
+        let preference = notification_preferences::Entity::find()
+            .filter(notification_preferences::Column::UserId.eq(user_id))
+            .filter(notification_preferences::Column::NotificationType.eq(notification_type))
+            .one(conn)
+            .await?;
+
+        Ok(preference.map(|p| p.enabled).unwrap_or(true))
+    }
+}
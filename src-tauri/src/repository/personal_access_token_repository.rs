@@ -0,0 +1,138 @@
+use sea_orm::*;
+use crate::auth::secrets::{hash_secret, random_secret};
+use crate::auth::Scope;
+use crate::entities::personal_access_tokens;
+use crate::models::personal_access_token_models::{
+    CreatedPersonalAccessTokenResponse, PersonalAccessTokenResponse,
+};
+use crate::error::{Result, AppError};
+
+pub const TOKEN_PREFIX: &str = "qop_";
+const DISPLAY_PREFIX_LEN: usize = 12;
+
+pub struct PersonalAccessTokenRepository {
+    db: DatabaseConnection,
+}
+
+impl PersonalAccessTokenRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create_token(
+        &self,
+        user_id: i32,
+        name: String,
+        scopes: Vec<Scope>,
+        expires_in_days: Option<i64>,
+    ) -> Result<CreatedPersonalAccessTokenResponse> {
+        if name.trim().is_empty() {
+            return Err(AppError::validation("name", "Token name cannot be empty"));
+        }
+
+        if scopes.is_empty() {
+            return Err(AppError::validation("scopes", "At least one scope is required"));
+        }
+
+        if matches!(expires_in_days, Some(days) if days <= 0) {
+            return Err(AppError::validation("expires_in_days", "Token expiry must be in the future"));
+        }
+
+        let token = format!("{}{}", TOKEN_PREFIX, random_secret(20));
+        let now = chrono::Utc::now();
+
+        let mut scope_names: Vec<&str> = scopes.iter().map(|s| s.as_str()).collect();
+        scope_names.sort_unstable();
+        scope_names.dedup();
+
+        let model = personal_access_tokens::ActiveModel {
+            user_id: Set(user_id),
+            name: Set(name.trim().to_string()),
+            token_prefix: Set(token[..DISPLAY_PREFIX_LEN].to_string()),
+            token_hash: Set(hash_secret(&token)),
+            scopes: Set(scope_names.join(" ")),
+            expires_at: Set(expires_in_days.map(|days| now + chrono::Duration::days(days))),
+            last_used_at: Set(None),
+            revoked_at: Set(None),
+            created_at: Set(now),
+            ..Default::default()
+        }.insert(&self.db).await?;
+
+        Ok(CreatedPersonalAccessTokenResponse {
+            token,
+            details: Self::to_response(model),
+        })
+    }
+
+    pub async fn list_tokens(&self, user_id: i32) -> Result<Vec<PersonalAccessTokenResponse>> {
+        let tokens = personal_access_tokens::Entity::find()
+            .filter(personal_access_tokens::Column::UserId.eq(user_id))
+            .order_by_desc(personal_access_tokens::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+
+        Ok(tokens.into_iter().map(Self::to_response).collect())
+    }
+
+    pub async fn revoke_token(&self, id: i32, user_id: i32) -> Result<()> {
+        let token = personal_access_tokens::Entity::find_by_id(id)
+            .filter(personal_access_tokens::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Token not found".to_string()))?;
+
+        if token.revoked_at.is_some() {
+            return Ok(());
+        }
+
+        let mut token_model: personal_access_tokens::ActiveModel = token.into();
+        token_model.revoked_at = Set(Some(chrono::Utc::now()));
+        token_model.update(&self.db).await?;
+
+        Ok(())
+    }
+
+    pub async fn authenticate(&self, token: &str) -> Result<(i32, Vec<Scope>)> {
+        let record = personal_access_tokens::Entity::find()
+            .filter(personal_access_tokens::Column::TokenHash.eq(hash_secret(token)))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::Unauthorized("Invalid access token".to_string()))?;
+
+        let now = chrono::Utc::now();
+
+        if record.revoked_at.is_some() {
+            return Err(AppError::Unauthorized("Access token has been revoked".to_string()));
+        }
+
+        if matches!(record.expires_at, Some(expires_at) if expires_at <= now) {
+            return Err(AppError::Unauthorized("Access token has expired".to_string()));
+        }
+
+        let user_id = record.user_id;
+        let scopes = Self::parse_scopes(&record.scopes);
+
+        let mut token_model: personal_access_tokens::ActiveModel = record.into();
+        token_model.last_used_at = Set(Some(now));
+        token_model.update(&self.db).await?;
+
+        Ok((user_id, scopes))
+    }
+
+    fn parse_scopes(scopes: &str) -> Vec<Scope> {
+        scopes.split_whitespace().filter_map(Scope::parse).collect()
+    }
+
+    fn to_response(token: personal_access_tokens::Model) -> PersonalAccessTokenResponse {
+        PersonalAccessTokenResponse {
+            id: token.id,
+            name: token.name,
+            token_prefix: token.token_prefix,
+            scopes: Self::parse_scopes(&token.scopes),
+            expires_at: token.expires_at,
+            last_used_at: token.last_used_at,
+            revoked_at: token.revoked_at,
+            created_at: token.created_at,
+        }
+    }
+}
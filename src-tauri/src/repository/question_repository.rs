@@ -1,15 +1,35 @@
-use crate::entities::{question_tags, questions, tags, users, votes};
+use crate::config::{ReviewConfig, TagConfig};
+use crate::entities::{answers, post_links, question_tags, questions, tag_preferences, tags, users, votes};
+use crate::entities::sea_orm_active_enums::{NotificationType, PostType, TagPreference, TargetType};
 use crate::error::{AppError, Result};
-use crate::models::question_models::{QuestionResponse, UserBrief};
+use crate::events::{ActivityEvent, EventBus};
+use crate::repository::answer_repository::AnswerRepository;
+use crate::repository::follow_repository::FollowRepository;
+use crate::repository::notification_repository::NotificationRepository;
+use crate::repository::review_repository::ReviewRepository;
+use crate::repository::search_repository::SearchRepository;
+use crate::repository::tag_repository::TagRepository;
+use crate::markdown;
+use crate::text;
+use crate::models::question_models::{QuestionQuery, QuestionResponse, RelatedQuestion, UserBrief};
+use crate::models::validation::{self, normalize_tag_name};
 use sea_orm::*;
+use sea_orm::sea_query::SelectStatement;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+const RELATED_CANDIDATES: u64 = 200;
+const RELATED_LIMIT: usize = 10;
+const LINK_MARKERS: [&str; 2] = ["/questions/", "/q/"];
 
 pub struct QuestionRepository {
     db: DatabaseConnection,
+    events: EventBus,
 }
 
 impl QuestionRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: DatabaseConnection, events: EventBus) -> Self {
+        Self { db, events }
     }
 
     pub async fn create_question(
@@ -18,11 +38,17 @@ impl QuestionRepository {
         title: String,
         content: String,
         tags: Vec<String>,
+        config: &TagConfig,
+        reviews: &ReviewConfig,
     ) -> Result<QuestionResponse> {
         let txn = self.db.begin().await?;
 
+        let tag_names = Self::canonical_tag_names(&txn, tags).await?;
+
         let question = questions::ActiveModel {
             title: Set(title),
+            rendered_html: Set(Some(markdown::render(&content, &tag_names))),
+            render_version: Set(markdown::RENDER_VERSION),
             content: Set(content),
             user_id: Set(user_id),
             created_at: Set(chrono::Utc::now()),
@@ -35,34 +61,169 @@ impl QuestionRepository {
         .insert(&txn)
         .await?;
 
+        Self::attach_tags(&txn, question.id, user_id, tag_names, config).await?;
+
+        Self::sync_links(&txn, PostType::Question, question.id, question.id, &question.content).await?;
+        SearchRepository::index_question(&txn, question.id, &question.title, &question.content).await?;
+        FollowRepository::follow(&txn, user_id, question.id).await?;
+        ReviewRepository::enqueue_post(&txn, PostType::Question, question.id, reviews.late_answer_days).await?;
+
+        txn.commit().await?;
+        self.get_question_by_id(question.id).await
+    }
+
+    pub async fn update_question(
+        &self,
+        id: i32,
+        user_id: i32,
+        title: Option<String>,
+        content: Option<String>,
+        tags: Option<Vec<String>>,
+        config: &TagConfig,
+    ) -> Result<QuestionResponse> {
+        let question = questions::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
+
+        if question.user_id != user_id {
+            return Err(AppError::Forbidden("You can only edit your own questions".to_string()));
+        }
+
+        if question.locked_at.is_some() {
+            return Err(AppError::Forbidden("This question is locked and cannot be edited".to_string()));
+        }
+
+        let txn = self.db.begin().await?;
+
+        let retagged = match tags {
+            Some(tags) => {
+                let tag_names = Self::canonical_tag_names(&txn, tags).await?;
+
+                question_tags::Entity::delete_many()
+                    .filter(question_tags::Column::QuestionId.eq(id))
+                    .exec(&txn)
+                    .await?;
+
+                Self::attach_tags(&txn, id, user_id, tag_names.clone(), config).await?;
+                Some(tag_names)
+            }
+            None => None,
+        };
+
+        let title = title.filter(|title| *title != question.title);
+        let content = content.filter(|content| *content != question.content);
+
+        if title.is_some() || content.is_some() {
+            Self::edit(&txn, user_id, id, title, content.unwrap_or(question.content)).await?;
+        } else if let Some(tag_names) = &retagged {
+            questions::ActiveModel {
+                id: Set(id),
+                rendered_html: Set(Some(markdown::render(&question.content, tag_names))),
+                render_version: Set(markdown::RENDER_VERSION),
+                ..Default::default()
+            }.update(&txn).await?;
+        }
+
+        if let Some(tag_names) = &retagged {
+            AnswerRepository::render_for_question(&txn, id, tag_names).await?;
+        }
+
+        txn.commit().await?;
+
+        self.get_question_by_id(id).await
+    }
+
+    pub async fn edit(
+        txn: &DatabaseTransaction,
+        editor_id: i32,
+        question_id: i32,
+        title: Option<String>,
+        content: String,
+    ) -> Result<()> {
+        if let Some(title) = &title {
+            validation::check("title", validation::question_title(title))?;
+        }
+        validation::check("content", validation::question_body(&content))?;
+
+        let tag_names = Self::tag_names(txn, question_id).await?;
+
+        let mut question = questions::ActiveModel {
+            id: Set(question_id),
+            rendered_html: Set(Some(markdown::render(&content, &tag_names))),
+            render_version: Set(markdown::RENDER_VERSION),
+            content: Set(content),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        if let Some(title) = title {
+            question.title = Set(title);
+        }
+        let question = question.update(txn).await?;
+
+        Self::sync_links(txn, PostType::Question, question_id, question_id, &question.content).await?;
+        SearchRepository::index_question(txn, question_id, &question.title, &question.content).await?;
+
+        NotificationRepository::notify_followers(
+            txn,
+            question_id,
+            Some(editor_id),
+            NotificationType::Edit,
+            PostType::Question,
+            question_id,
+            &[],
+        ).await
+    }
+
+    async fn canonical_tag_names(txn: &DatabaseTransaction, tags: Vec<String>) -> Result<Vec<String>> {
+        let mut tag_names: Vec<String> = Vec::new();
         for tag_name in tags {
+            let tag_name = TagRepository::canonical_name(txn, &normalize_tag_name(&tag_name)).await?;
+            if !tag_names.contains(&tag_name) {
+                tag_names.push(tag_name);
+            }
+        }
+        tag_names.sort();
+
+        Ok(tag_names)
+    }
+
+    async fn attach_tags(
+        txn: &DatabaseTransaction,
+        question_id: i32,
+        user_id: i32,
+        tag_names: Vec<String>,
+        config: &TagConfig,
+    ) -> Result<()> {
+        for tag_name in tag_names {
             let tag = match tags::Entity::find()
                 .filter(tags::Column::Name.eq(&tag_name))
-                .one(&txn)
+                .one(txn)
                 .await?
             {
                 Some(t) => t,
                 None => {
+                    TagRepository::ensure_can_create(txn, user_id, config).await?;
+
                     tags::ActiveModel {
                         name: Set(tag_name),
                         ..Default::default()
                     }
-                    .insert(&txn)
+                    .insert(txn)
                     .await?
                 }
             };
 
             question_tags::ActiveModel {
-                question_id: Set(question.id),
+                question_id: Set(question_id),
                 tag_id: Set(tag.id),
                 ..Default::default()
             }
-            .insert(&txn)
+            .insert(txn)
             .await?;
         }
 
-        txn.commit().await?;
-        self.get_question_by_id(question.id).await
+        Ok(())
     }
 
     pub async fn get_question_by_id(&self, id: i32) -> Result<QuestionResponse> {
@@ -78,17 +239,92 @@ impl QuestionRepository {
         self.transform_to_response(question, tag_names).await
     }
 
+    pub async fn set_closed(&self, id: i32, closed: bool) -> Result<QuestionResponse> {
+        let question = questions::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
+
+        if (question.is_closed != 0) == closed {
+            let state = if closed { "closed" } else { "open" };
+            return Err(AppError::Conflict(format!("This question is already {}", state)));
+        }
+
+        questions::ActiveModel {
+            id: Set(id),
+            is_closed: Set(if closed { 1 } else { 0 }),
+            ..Default::default()
+        }
+        .update(&self.db)
+        .await?;
+
+        let event = if closed {
+            ActivityEvent::QuestionClosed { question_id: id }
+        } else {
+            ActivityEvent::QuestionReopened { question_id: id }
+        };
+        self.events.publish(event);
+
+        self.get_question_by_id(id).await
+    }
+
     pub async fn list_questions(
         &self,
-        page: u32,
-        per_page: u32,
-        sort_by: Option<String>,
-        _tag: Option<String>,
-        search: Option<String>,
+        filters: QuestionQuery,
+        viewer_id: Option<i32>,
     ) -> Result<Vec<QuestionResponse>> {
         let mut query = questions::Entity::find();
 
-        if let Some(search_term) = search {
+        let mut watched_tags = HashSet::new();
+        let mut ignored_tags = HashSet::new();
+        if let Some(viewer_id) = viewer_id {
+            let viewer = users::Entity::find_by_id(viewer_id)
+                .one(&self.db)
+                .await?
+                .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+            let preferences = tag_preferences::Entity::find()
+                .filter(tag_preferences::Column::UserId.eq(viewer_id))
+                .find_also_related(tags::Entity)
+                .all(&self.db)
+                .await?;
+
+            for (preference, tag) in preferences {
+                let Some(tag) = tag else { continue };
+                match preference.preference {
+                    TagPreference::Watched => watched_tags.insert(tag.name),
+                    TagPreference::Ignored => ignored_tags.insert(tag.name),
+                };
+            }
+
+            if viewer.hide_ignored_tags && !ignored_tags.is_empty() {
+                query = query.filter(
+                    questions::Column::Id.not_in_subquery(Self::tagged_with(viewer_id, TagPreference::Ignored)),
+                );
+            }
+
+            if filters.boost_watched.unwrap_or(false) && !watched_tags.is_empty() {
+                query = query.order_by_desc(
+                    questions::Column::Id.in_subquery(Self::tagged_with(viewer_id, TagPreference::Watched)),
+                );
+            }
+        }
+
+        if let Some(tag) = filters.tag {
+            let tag = TagRepository::canonical_name(&self.db, &normalize_tag_name(&tag)).await?;
+            query = query.filter(
+                questions::Column::Id.in_subquery(
+                    question_tags::Entity::find()
+                        .select_only()
+                        .column(question_tags::Column::QuestionId)
+                        .join(JoinType::InnerJoin, question_tags::Relation::Tags.def())
+                        .filter(tags::Column::Name.eq(tag))
+                        .into_query(),
+                ),
+            );
+        }
+
+        if let Some(search_term) = filters.search {
             query = query.filter(
                 Condition::any()
                     .add(questions::Column::Title.contains(&search_term))
@@ -96,7 +332,7 @@ impl QuestionRepository {
             );
         }
 
-        if let Some(sort) = sort_by {
+        if let Some(sort) = filters.sort_by {
             match sort.as_str() {
                 "newest" => query = query.order_by_desc(questions::Column::CreatedAt),
                 "oldest" => query = query.order_by_asc(questions::Column::CreatedAt),
@@ -107,6 +343,8 @@ impl QuestionRepository {
             query = query.order_by_desc(questions::Column::CreatedAt);
         }
 
+        let page = filters.page.unwrap_or(1);
+        let per_page = filters.per_page.unwrap_or(10);
         let offset = (page - 1) * per_page;
         query = query.offset(offset as u64).limit(per_page as u64);
 
@@ -116,14 +354,221 @@ impl QuestionRepository {
         for question in questions {
             let tags = question.find_related(tags::Entity).all(&self.db).await?;
 
-            let tag_names = tags.into_iter().map(|tag| tag.name).collect();
+            let tag_names: Vec<String> = tags.into_iter().map(|tag| tag.name).collect();
+            let is_watched = tag_names.iter().any(|name| watched_tags.contains(name));
+            let is_ignored = tag_names.iter().any(|name| ignored_tags.contains(name));
 
-            responses.push(self.transform_to_response(question, tag_names).await?);
+            let mut response = self.transform_to_response(question, tag_names).await?;
+            response.is_watched = is_watched;
+            response.is_ignored = is_ignored;
+            responses.push(response);
         }
 
         Ok(responses)
     }
 
+    pub async fn get_related_questions(&self, id: i32) -> Result<Vec<RelatedQuestion>> {
+        let question = questions::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
+
+        let tag_ids: Vec<i32> = question_tags::Entity::find()
+            .select_only()
+            .column(question_tags::Column::TagId)
+            .filter(question_tags::Column::QuestionId.eq(id))
+            .into_tuple::<i32>()
+            .all(&self.db)
+            .await?;
+
+        if tag_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let overlaps: HashMap<i32, i64> = question_tags::Entity::find()
+            .select_only()
+            .column(question_tags::Column::QuestionId)
+            .column_as(question_tags::Column::TagId.count(), "overlap")
+            .filter(question_tags::Column::TagId.is_in(tag_ids.clone()))
+            .filter(question_tags::Column::QuestionId.ne(id))
+            .group_by(question_tags::Column::QuestionId)
+            .order_by_desc(question_tags::Column::TagId.count())
+            .order_by_desc(question_tags::Column::QuestionId)
+            .limit(RELATED_CANDIDATES)
+            .into_tuple::<(i32, i64)>()
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .collect();
+
+        let candidates = questions::Entity::find()
+            .filter(questions::Column::Id.is_in(overlaps.keys().copied()))
+            .all(&self.db)
+            .await?;
+
+        let title_tokens: HashSet<String> = text::tokenize(&question.title).into_iter().collect();
+        let mut scored: Vec<(f64, questions::Model)> = candidates
+            .into_iter()
+            .map(|candidate| {
+                let tokens: HashSet<String> = text::tokenize(&candidate.title).into_iter().collect();
+                let overlap = overlaps.get(&candidate.id).copied().unwrap_or(0) as f64 / tag_ids.len() as f64;
+                (overlap + text::jaccard(&title_tokens, &tokens), candidate)
+            })
+            .collect();
+
+        scored.sort_by(|(a_score, a), (b_score, b)| {
+            b_score.partial_cmp(a_score).unwrap_or(Ordering::Equal).then(b.id.cmp(&a.id))
+        });
+        scored.truncate(RELATED_LIMIT);
+
+        self.to_related(scored.into_iter().map(|(_, question)| question).collect()).await
+    }
+
+    pub async fn get_linked_questions(&self, id: i32) -> Result<Vec<RelatedQuestion>> {
+        let links = post_links::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(post_links::Column::QuestionId.eq(id))
+                    .add(post_links::Column::LinkedQuestionId.eq(id)),
+            )
+            .all(&self.db)
+            .await?;
+
+        let linked_ids: HashSet<i32> = links
+            .into_iter()
+            .map(|link| if link.question_id == id { link.linked_question_id } else { link.question_id })
+            .filter(|linked_id| *linked_id != id)
+            .collect();
+
+        let linked = questions::Entity::find()
+            .filter(questions::Column::Id.is_in(linked_ids))
+            .order_by_desc(questions::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+
+        self.to_related(linked).await
+    }
+
+    pub async fn sync_links<C: ConnectionTrait>(
+        conn: &C,
+        post_type: PostType,
+        post_id: i32,
+        question_id: i32,
+        content: &str,
+    ) -> Result<()> {
+        post_links::Entity::delete_many()
+            .filter(post_links::Column::PostType.eq(post_type))
+            .filter(post_links::Column::PostId.eq(post_id))
+            .exec(conn)
+            .await?;
+
+        let referenced: Vec<i32> = Self::parse_links(content)
+            .into_iter()
+            .filter(|linked_id| *linked_id != question_id)
+            .collect();
+        if referenced.is_empty() {
+            return Ok(());
+        }
+
+        let existing: Vec<i32> = questions::Entity::find()
+            .select_only()
+            .column(questions::Column::Id)
+            .filter(questions::Column::Id.is_in(referenced))
+            .into_tuple::<i32>()
+            .all(conn)
+            .await?;
+
+        if !existing.is_empty() {
+            let now = chrono::Utc::now();
+            post_links::Entity::insert_many(existing.into_iter().map(|linked_id| post_links::ActiveModel {
+                post_type: Set(post_type),
+                post_id: Set(post_id),
+                question_id: Set(question_id),
+                linked_question_id: Set(linked_id),
+                created_at: Set(now),
+                ..Default::default()
+            }))
+            .exec(conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    fn parse_links(content: &str) -> Vec<i32> {
+        let mut ids: Vec<i32> = Vec::new();
+
+        for marker in LINK_MARKERS {
+            for (index, _) in content.match_indices(marker) {
+                let preceding = content[..index].chars().next_back();
+                if !preceding.is_none_or(|c| c.is_whitespace() || matches!(c, '(' | '<' | '"' | '\'')) {
+                    continue;
+                }
+
+                let digits: String = content[index + marker.len()..]
+                    .chars()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect();
+
+                if let Ok(id) = digits.parse::<i32>() {
+                    if !ids.contains(&id) {
+                        ids.push(id);
+                    }
+                }
+            }
+        }
+
+        ids
+    }
+
+    async fn to_related(&self, questions: Vec<questions::Model>) -> Result<Vec<RelatedQuestion>> {
+        let ids: Vec<i32> = questions.iter().map(|question| question.id).collect();
+        let answer_counts: HashMap<i32, i64> = answers::Entity::find()
+            .select_only()
+            .column(answers::Column::QuestionId)
+            .column_as(answers::Column::Id.count(), "count")
+            .filter(answers::Column::QuestionId.is_in(ids))
+            .group_by(answers::Column::QuestionId)
+            .into_tuple::<(i32, i64)>()
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(questions
+            .into_iter()
+            .map(|question| RelatedQuestion {
+                id: question.id,
+                answer_count: answer_counts.get(&question.id).copied().unwrap_or(0) as i32,
+                is_answered: question.is_answered != 0,
+                title: question.title,
+            })
+            .collect())
+    }
+
+    pub async fn tag_names<C: ConnectionTrait>(conn: &C, question_id: i32) -> Result<Vec<String>> {
+        Ok(tags::Entity::find()
+            .inner_join(question_tags::Entity)
+            .filter(question_tags::Column::QuestionId.eq(question_id))
+            .order_by_asc(tags::Column::Name)
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect())
+    }
+
+    fn tagged_with(user_id: i32, preference: TagPreference) -> SelectStatement {
+        question_tags::Entity::find()
+            .select_only()
+            .column(question_tags::Column::QuestionId)
+            .join(JoinType::InnerJoin, question_tags::Relation::Tags.def())
+            .join(JoinType::InnerJoin, tags::Relation::TagPreferences.def())
+            .filter(tag_preferences::Column::UserId.eq(user_id))
+            .filter(tag_preferences::Column::Preference.eq(preference))
+            .into_query()
+    }
+
     async fn transform_to_response(
         &self,
         question: questions::Model,
@@ -134,7 +579,6 @@ impl QuestionRepository {
             .await?
             .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-        use crate::entities::answers;
         let answer_count = answers::Entity::find()
             .filter(answers::Column::QuestionId.eq(question.id))
             .count(&self.db)
@@ -142,17 +586,18 @@ impl QuestionRepository {
 
         let votes = votes::Entity::find()
             .filter(votes::Column::TargetId.eq(question.id))
-            .filter(votes::Column::TargetType.eq("question"))
+            .filter(votes::Column::TargetType.eq(TargetType::Question))
             .all(&self.db)
             .await?;
 
         let vote_count = votes.iter().fold(0, |acc, vote| {
-            acc + if vote.vote_type == "up" { 1 } else { -1 }
+            acc + vote.vote_type.score()
         });
 
         Ok(QuestionResponse {
             id: question.id,
             title: question.title,
+            rendered_html: question.rendered_html.unwrap_or_else(|| markdown::render(&question.content, &tags)),
             content: question.content,
             user_id: question.user_id,
             created_at: question.created_at,
@@ -170,6 +615,8 @@ impl QuestionRepository {
             },
             answer_count,
             vote_count,
+            is_watched: false,
+            is_ignored: false,
         })
     }
 }
@@ -0,0 +1,111 @@
+use sea_orm::*;
+use crate::entities::{answers, comments, questions};
+use crate::markdown;
+use crate::repository::question_repository::QuestionRepository;
+use crate::error::Result;
+
+const RENDER_BATCH: u64 = 200;
+
+pub struct RenderRepository {
+    db: DatabaseConnection,
+}
+
+impl RenderRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn render_missing(&self) -> Result<u64> {
+        let mut rendered = 0;
+
+        loop {
+            let batch = questions::Entity::find()
+                .filter(
+                    Condition::any()
+                        .add(questions::Column::RenderedHtml.is_null())
+                        .add(questions::Column::RenderVersion.lt(markdown::RENDER_VERSION)),
+                )
+                .order_by_asc(questions::Column::Id)
+                .limit(RENDER_BATCH)
+                .all(&self.db)
+                .await?;
+            if batch.is_empty() {
+                break;
+            }
+
+            let txn = self.db.begin().await?;
+            for question in &batch {
+                let tag_names = QuestionRepository::tag_names(&txn, question.id).await?;
+
+                questions::ActiveModel {
+                    id: Set(question.id),
+                    rendered_html: Set(Some(markdown::render(&question.content, &tag_names))),
+                    render_version: Set(markdown::RENDER_VERSION),
+                    ..Default::default()
+                }.update(&txn).await?;
+            }
+            txn.commit().await?;
+            rendered += batch.len() as u64;
+        }
+
+        loop {
+            let batch = answers::Entity::find()
+                .filter(
+                    Condition::any()
+                        .add(answers::Column::RenderedHtml.is_null())
+                        .add(answers::Column::RenderVersion.lt(markdown::RENDER_VERSION)),
+                )
+                .order_by_asc(answers::Column::Id)
+                .limit(RENDER_BATCH)
+                .all(&self.db)
+                .await?;
+            if batch.is_empty() {
+                break;
+            }
+
+            let txn = self.db.begin().await?;
+            for answer in &batch {
+                let tag_names = QuestionRepository::tag_names(&txn, answer.question_id).await?;
+
+                answers::ActiveModel {
+                    id: Set(answer.id),
+                    rendered_html: Set(Some(markdown::render(&answer.content, &tag_names))),
+                    render_version: Set(markdown::RENDER_VERSION),
+                    ..Default::default()
+                }.update(&txn).await?;
+            }
+            txn.commit().await?;
+            rendered += batch.len() as u64;
+        }
+
+        loop {
+            let batch = comments::Entity::find()
+                .filter(
+                    Condition::any()
+                        .add(comments::Column::RenderedHtml.is_null())
+                        .add(comments::Column::RenderVersion.lt(markdown::RENDER_VERSION)),
+                )
+                .order_by_asc(comments::Column::Id)
+                .limit(RENDER_BATCH)
+                .all(&self.db)
+                .await?;
+            if batch.is_empty() {
+                break;
+            }
+
+            let txn = self.db.begin().await?;
+            for comment in &batch {
+                comments::ActiveModel {
+                    id: Set(comment.id),
+                    rendered_html: Set(Some(markdown::render(&comment.content, &[]))),
+                    render_version: Set(markdown::RENDER_VERSION),
+                    ..Default::default()
+                }.update(&txn).await?;
+            }
+            txn.commit().await?;
+            rendered += batch.len() as u64;
+        }
+
+        Ok(rendered)
+    }
+}
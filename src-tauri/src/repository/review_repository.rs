@@ -0,0 +1,529 @@
+use sea_orm::*;
+use sea_orm::sea_query::Query;
+use std::collections::{HashMap, HashSet};
+use crate::config::ReviewConfig;
+use crate::entities::{answers, flags, questions, review_decisions, review_tasks, users};
+use crate::entities::sea_orm_active_enums::{
+    FlagReason, FlagStatus, PostType, ReviewAction, ReviewQueue, ReviewStatus,
+};
+use crate::models::review_models::{
+    ReviewActionCount, ReviewQueueSummary, ReviewTaskResponse, SubmitReviewRequest, UserBrief,
+};
+use crate::repository::answer_repository::AnswerRepository;
+use crate::repository::comment_repository::CommentRepository;
+use crate::repository::question_repository::QuestionRepository;
+use crate::error::{AppError, Result};
+
+const MIN_POST_LENGTH: usize = 60;
+const MIN_PROSE_WORDS: usize = 5;
+
+pub struct ReviewRepository {
+    db: DatabaseConnection,
+}
+
+struct ReviewedPost {
+    question_id: i32,
+    title: String,
+    content: String,
+    author_id: i32,
+}
+
+impl ReviewRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn enqueue_post<C: ConnectionTrait>(
+        conn: &C,
+        post_type: PostType,
+        post_id: i32,
+        late_answer_days: i64,
+    ) -> Result<()> {
+        let mut queues = Vec::new();
+
+        let (author_id, content) = match post_type {
+            PostType::Question => {
+                let question = questions::Entity::find_by_id(post_id)
+                    .one(conn)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
+
+                (question.user_id, question.content)
+            }
+            PostType::Answer => {
+                let answer = answers::Entity::find_by_id(post_id)
+                    .one(conn)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound("Answer not found".to_string()))?;
+
+                let question = questions::Entity::find_by_id(answer.question_id)
+                    .one(conn)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
+
+                if answer.created_at - question.created_at > chrono::Duration::days(late_answer_days) {
+                    queues.push(ReviewQueue::LateAnswer);
+                }
+
+                (answer.user_id, answer.content)
+            }
+            PostType::Comment => return Ok(()),
+        };
+
+        let question_count = questions::Entity::find()
+            .filter(questions::Column::UserId.eq(author_id))
+            .count(conn)
+            .await?;
+
+        let answer_count = answers::Entity::find()
+            .filter(answers::Column::UserId.eq(author_id))
+            .count(conn)
+            .await?;
+
+        if question_count + answer_count == 1 {
+            queues.push(ReviewQueue::FirstPost);
+        }
+
+        if Self::is_low_quality(&content) {
+            queues.push(ReviewQueue::LowQuality);
+        }
+
+        for queue in queues {
+            review_tasks::ActiveModel {
+                queue: Set(queue),
+                post_type: Set(post_type),
+                post_id: Set(post_id),
+                status: Set(ReviewStatus::Open),
+                created_at: Set(chrono::Utc::now()),
+                ..Default::default()
+            }.insert(conn).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_summaries(&self) -> Result<Vec<ReviewQueueSummary>> {
+        let mut summaries = Vec::new();
+
+        for queue in [ReviewQueue::FirstPost, ReviewQueue::LateAnswer, ReviewQueue::LowQuality] {
+            let open_count = review_tasks::Entity::find()
+                .filter(review_tasks::Column::Queue.eq(queue))
+                .filter(review_tasks::Column::Status.eq(ReviewStatus::Open))
+                .count(&self.db)
+                .await?;
+
+            summaries.push(ReviewQueueSummary { queue, open_count });
+        }
+
+        Ok(summaries)
+    }
+
+    pub async fn get_queue(
+        &self,
+        user_id: i32,
+        queue: ReviewQueue,
+        page: u64,
+        per_page: u64,
+        config: &ReviewConfig,
+    ) -> Result<Vec<ReviewTaskResponse>> {
+        self.ensure_can_review(user_id, config).await?;
+
+        let page = page.max(1);
+        let per_page = per_page.clamp(1, 100);
+
+        let reviewed = Query::select()
+            .column(review_decisions::Column::TaskId)
+            .from(review_decisions::Entity)
+            .and_where(review_decisions::Column::UserId.eq(user_id))
+            .to_owned();
+        let others_questions = Query::select()
+            .column(questions::Column::Id)
+            .from(questions::Entity)
+            .and_where(questions::Column::UserId.ne(user_id))
+            .to_owned();
+        let others_answers = Query::select()
+            .column(answers::Column::Id)
+            .from(answers::Entity)
+            .and_where(answers::Column::UserId.ne(user_id))
+            .to_owned();
+
+        let tasks = review_tasks::Entity::find()
+            .filter(review_tasks::Column::Queue.eq(queue))
+            .filter(review_tasks::Column::Status.eq(ReviewStatus::Open))
+            .filter(review_tasks::Column::Id.not_in_subquery(reviewed))
+            .filter(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(review_tasks::Column::PostType.eq(PostType::Question))
+                            .add(review_tasks::Column::PostId.in_subquery(others_questions)),
+                    )
+                    .add(
+                        Condition::all()
+                            .add(review_tasks::Column::PostType.eq(PostType::Answer))
+                            .add(review_tasks::Column::PostId.in_subquery(others_answers)),
+                    ),
+            )
+            .order_by_asc(review_tasks::Column::CreatedAt)
+            .order_by_asc(review_tasks::Column::Id)
+            .offset((page - 1) * per_page)
+            .limit(per_page)
+            .all(&self.db)
+            .await?;
+
+        let keys: Vec<(PostType, i32)> = tasks.iter().map(|task| (task.post_type, task.post_id)).collect();
+        let mut posts = Self::load_posts(&self.db, &keys).await?;
+
+        let tasks = tasks
+            .into_iter()
+            .filter_map(|task| {
+                let post = posts.remove(&(task.post_type, task.post_id))?;
+                Some((task, post))
+            })
+            .collect();
+
+        self.to_responses(tasks).await
+    }
+
+    pub async fn submit_review(
+        &self,
+        user_id: i32,
+        task_id: i32,
+        request: SubmitReviewRequest,
+        config: &ReviewConfig,
+    ) -> Result<ReviewTaskResponse> {
+        self.ensure_can_review(user_id, config).await?;
+
+        if request.action != ReviewAction::Edit && (request.title.is_some() || request.content.is_some()) {
+            return Err(AppError::validation("action", "Choose the edit action to change the post"));
+        }
+
+        let task = review_tasks::Entity::find_by_id(task_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Review task not found".to_string()))?;
+
+        let post = Self::load_post(&self.db, task.post_type, task.post_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("The reviewed post no longer exists".to_string()))?;
+
+        if post.author_id == user_id {
+            return Err(AppError::Forbidden("You cannot review your own post".to_string()));
+        }
+
+        let txn = self.db.begin().await?;
+
+        let task = review_tasks::Entity::find_by_id(task.id)
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .filter(|task| task.status == ReviewStatus::Open)
+            .ok_or_else(|| AppError::Conflict("This review task has already been completed".to_string()))?;
+
+        let already_reviewed = review_decisions::Entity::find()
+            .filter(review_decisions::Column::TaskId.eq(task.id))
+            .filter(review_decisions::Column::UserId.eq(user_id))
+            .count(&txn)
+            .await? > 0;
+
+        if already_reviewed {
+            return Err(AppError::Conflict("You have already reviewed this post".to_string()));
+        }
+
+        if request.action == ReviewAction::Edit {
+            let content = request
+                .content
+                .ok_or_else(|| AppError::validation("content", "Provide the edited content"))?;
+            match task.post_type {
+                PostType::Question => {
+                    QuestionRepository::edit(&txn, user_id, task.post_id, request.title, content).await?;
+                }
+                _ if request.title.is_some() => {
+                    return Err(AppError::validation("title", "Only questions have a title"));
+                }
+                PostType::Answer => AnswerRepository::edit(&txn, user_id, task.post_id, content).await?,
+                PostType::Comment => CommentRepository::edit(&txn, task.post_id, content).await?,
+            }
+        }
+
+        review_decisions::ActiveModel {
+            task_id: Set(task.id),
+            user_id: Set(user_id),
+            action: Set(request.action),
+            note: Set(request.note),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }.insert(&txn).await?;
+
+        let decisions = Self::count_decisions(&txn, task.id).await?;
+        let outcome = decisions
+            .iter()
+            .find(|d| d.count >= config.consensus)
+            .map(|d| d.action);
+
+        let task = match outcome {
+            Some(outcome) => {
+                if outcome == ReviewAction::RecommendDeletion {
+                    Self::flag_for_deletion(&txn, &task).await?;
+                }
+
+                let completed_at = chrono::Utc::now();
+                let result = review_tasks::Entity::update_many()
+                    .filter(review_tasks::Column::Id.eq(task.id))
+                    .filter(review_tasks::Column::Status.eq(ReviewStatus::Open))
+                    .set(review_tasks::ActiveModel {
+                        status: Set(ReviewStatus::Completed),
+                        outcome: Set(Some(outcome)),
+                        completed_at: Set(Some(completed_at)),
+                        ..Default::default()
+                    })
+                    .exec(&txn)
+                    .await?;
+
+                if result.rows_affected == 0 {
+                    return Err(AppError::Conflict("This review task has already been completed".to_string()));
+                }
+
+                review_tasks::Model {
+                    status: ReviewStatus::Completed,
+                    outcome: Some(outcome),
+                    completed_at: Some(completed_at),
+                    ..task
+                }
+            }
+            None => task,
+        };
+
+        txn.commit().await?;
+
+        let post = Self::load_post(&self.db, task.post_type, task.post_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("The reviewed post no longer exists".to_string()))?;
+
+        self.to_response(task, post).await
+    }
+
+    async fn ensure_can_review(&self, user_id: i32, config: &ReviewConfig) -> Result<()> {
+        let user = users::Entity::find_by_id(user_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        if user.reputation < config.min_reputation && !user.is_moderator {
+            return Err(AppError::Forbidden(format!(
+                "You need at least {} reputation to review posts",
+                config.min_reputation
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn flag_for_deletion(txn: &DatabaseTransaction, task: &review_tasks::Model) -> Result<()> {
+        let recommenders: Vec<i32> = review_decisions::Entity::find()
+            .select_only()
+            .column(review_decisions::Column::UserId)
+            .filter(review_decisions::Column::TaskId.eq(task.id))
+            .filter(review_decisions::Column::Action.eq(ReviewAction::RecommendDeletion))
+            .into_tuple::<i32>()
+            .all(txn)
+            .await?;
+
+        let already_flagged: HashSet<i32> = flags::Entity::find()
+            .select_only()
+            .column(flags::Column::UserId)
+            .filter(flags::Column::PostType.eq(task.post_type))
+            .filter(flags::Column::PostId.eq(task.post_id))
+            .into_tuple::<i32>()
+            .all(txn)
+            .await?
+            .into_iter()
+            .collect();
+
+        for user_id in recommenders.into_iter().filter(|id| !already_flagged.contains(id)) {
+            flags::ActiveModel {
+                post_type: Set(task.post_type),
+                post_id: Set(task.post_id),
+                user_id: Set(user_id),
+                reason: Set(FlagReason::Other),
+                text: Set(Some(format!("Recommended for deletion in the {} review queue", task.queue.as_str()))),
+                status: Set(FlagStatus::Pending),
+                created_at: Set(chrono::Utc::now()),
+                ..Default::default()
+            }.insert(txn).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn count_decisions<C: ConnectionTrait>(conn: &C, task_id: i32) -> Result<Vec<ReviewActionCount>> {
+        Ok(Self::count_decisions_for(conn, vec![task_id]).await?.remove(&task_id).unwrap_or_default())
+    }
+
+    async fn count_decisions_for<C: ConnectionTrait>(
+        conn: &C,
+        task_ids: Vec<i32>,
+    ) -> Result<HashMap<i32, Vec<ReviewActionCount>>> {
+        let rows = review_decisions::Entity::find()
+            .select_only()
+            .column(review_decisions::Column::TaskId)
+            .column(review_decisions::Column::Action)
+            .column_as(review_decisions::Column::Id.count(), "count")
+            .filter(review_decisions::Column::TaskId.is_in(task_ids))
+            .group_by(review_decisions::Column::TaskId)
+            .group_by(review_decisions::Column::Action)
+            .into_tuple::<(i32, ReviewAction, i64)>()
+            .all(conn)
+            .await?;
+
+        let mut decisions: HashMap<i32, Vec<ReviewActionCount>> = HashMap::new();
+        for (task_id, action, count) in rows {
+            decisions
+                .entry(task_id)
+                .or_default()
+                .push(ReviewActionCount { action, count: count as u64 });
+        }
+
+        Ok(decisions)
+    }
+
+    async fn load_post<C: ConnectionTrait>(conn: &C, post_type: PostType, post_id: i32) -> Result<Option<ReviewedPost>> {
+        Ok(Self::load_posts(conn, &[(post_type, post_id)]).await?.remove(&(post_type, post_id)))
+    }
+
+    async fn load_posts<C: ConnectionTrait>(
+        conn: &C,
+        keys: &[(PostType, i32)],
+    ) -> Result<HashMap<(PostType, i32), ReviewedPost>> {
+        let ids = |post_type: PostType| -> Vec<i32> {
+            keys.iter().filter(|(t, _)| *t == post_type).map(|(_, id)| *id).collect()
+        };
+
+        let answers = answers::Entity::find()
+            .filter(answers::Column::Id.is_in(ids(PostType::Answer)))
+            .all(conn)
+            .await?;
+        let question_ids: HashSet<i32> = ids(PostType::Question)
+            .into_iter()
+            .chain(answers.iter().map(|answer| answer.question_id))
+            .collect();
+        let questions: HashMap<i32, questions::Model> = questions::Entity::find()
+            .filter(questions::Column::Id.is_in(question_ids))
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|question| (question.id, question))
+            .collect();
+
+        let mut posts = HashMap::new();
+        for id in ids(PostType::Question) {
+            if let Some(question) = questions.get(&id) {
+                posts.insert((PostType::Question, id), ReviewedPost {
+                    question_id: question.id,
+                    title: question.title.clone(),
+                    content: question.content.clone(),
+                    author_id: question.user_id,
+                });
+            }
+        }
+
+        for answer in answers {
+            let title = questions
+                .get(&answer.question_id)
+                .map(|q| q.title.clone())
+                .unwrap_or_default();
+
+            posts.insert((PostType::Answer, answer.id), ReviewedPost {
+                question_id: answer.question_id,
+                title,
+                content: answer.content,
+                author_id: answer.user_id,
+            });
+        }
+
+        Ok(posts)
+    }
+
+    async fn to_response(&self, task: review_tasks::Model, post: ReviewedPost) -> Result<ReviewTaskResponse> {
+        self.to_responses(vec![(task, post)])
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))
+    }
+
+    async fn to_responses(&self, tasks: Vec<(review_tasks::Model, ReviewedPost)>) -> Result<Vec<ReviewTaskResponse>> {
+        let author_ids: HashSet<i32> = tasks.iter().map(|(_, post)| post.author_id).collect();
+        let authors: HashMap<i32, users::Model> = users::Entity::find()
+            .filter(users::Column::Id.is_in(author_ids))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|user| (user.id, user))
+            .collect();
+        let mut decisions = Self::count_decisions_for(&self.db, tasks.iter().map(|(task, _)| task.id).collect()).await?;
+
+        tasks
+            .into_iter()
+            .map(|(task, post)| {
+                let author = authors
+                    .get(&post.author_id)
+                    .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+                Ok(ReviewTaskResponse {
+                    id: task.id,
+                    queue: task.queue,
+                    post_type: task.post_type,
+                    post_id: task.post_id,
+                    question_id: post.question_id,
+                    title: post.title,
+                    content: post.content,
+                    author: UserBrief {
+                        id: author.id,
+                        username: author.username.clone(),
+                        display_name: author.display_name.clone(),
+                        reputation: author.reputation,
+                        avatar_url: author.avatar_url.clone(),
+                    },
+                    status: task.status,
+                    outcome: task.outcome,
+                    decisions: decisions.remove(&task.id).unwrap_or_default(),
+                    created_at: task.created_at,
+                    completed_at: task.completed_at,
+                })
+            })
+            .collect()
+    }
+
+    fn is_low_quality(content: &str) -> bool {
+        let mut prose = Vec::new();
+        let mut has_code = false;
+        let mut in_fence = false;
+
+        for line in content.lines() {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+                has_code = true;
+                continue;
+            }
+
+            if in_fence || line.starts_with("    ") || line.starts_with('\t') {
+                has_code = true;
+                continue;
+            }
+
+            prose.extend(line.split_whitespace());
+        }
+
+        let links = prose
+            .iter()
+            .filter(|word| word.contains("http://") || word.contains("https://"))
+            .count();
+        let words = prose.len() - links;
+
+        let code_only = has_code && words < MIN_PROSE_WORDS;
+        let link_only = links > 0 && words < MIN_PROSE_WORDS;
+        let too_short = content.trim().chars().count() < MIN_POST_LENGTH;
+
+        code_only || link_only || too_short
+    }
+}
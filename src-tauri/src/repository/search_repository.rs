@@ -0,0 +1,317 @@
+use sea_orm::*;
+use sea_orm::sea_query::{CaseStatement, Func, OnConflict, SimpleExpr};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use crate::entities::{answers, question_tags, questions, search_documents, search_terms, tags};
+use crate::models::question_models::SimilarQuestion;
+use crate::models::validation::normalize_tag_name;
+use crate::repository::tag_repository::TagRepository;
+use crate::text;
+use crate::error::Result;
+
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+const TITLE_WEIGHT: i32 = 3;
+const TAG_WEIGHT: f64 = 1.5;
+const MAX_TERM_LENGTH: usize = 64;
+const MAX_QUERY_TERMS: usize = 48;
+const CANDIDATE_DOCUMENTS: u64 = 1000;
+const SIMILAR_CANDIDATES: usize = 200;
+const SIMILAR_LIMIT: usize = 5;
+const INDEX_BATCH: u64 = 200;
+
+pub struct SearchRepository {
+    db: DatabaseConnection,
+}
+
+impl SearchRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn suggest_similar(
+        &self,
+        title: &str,
+        content: &str,
+        tag_names: Vec<String>,
+    ) -> Result<Vec<SimilarQuestion>> {
+        let (title_counts, _) = Self::term_counts(title);
+        let (body_counts, _) = Self::term_counts(content);
+
+        let mut weights: HashMap<String, i32> = HashMap::new();
+        for (term, count) in title_counts {
+            *weights.entry(term).or_insert(0) += count * TITLE_WEIGHT;
+        }
+        for (term, count) in body_counts {
+            *weights.entry(term).or_insert(0) += count;
+        }
+
+        let mut terms: Vec<(String, i32)> = weights.into_iter().collect();
+        terms.sort_by(|(a_term, a), (b_term, b)| b.cmp(a).then(a_term.cmp(b_term)));
+        let terms: Vec<String> = terms
+            .into_iter()
+            .take(MAX_QUERY_TERMS)
+            .map(|(term, _)| term)
+            .collect();
+
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let document_count = search_documents::Entity::find().count(&self.db).await? as f64;
+        if document_count == 0.0 {
+            return Ok(Vec::new());
+        }
+
+        let (title_total, body_total) = search_documents::Entity::find()
+            .select_only()
+            .column_as(search_documents::Column::TitleLength.sum(), "title_total")
+            .column_as(search_documents::Column::BodyLength.sum(), "body_total")
+            .into_tuple::<(Option<i64>, Option<i64>)>()
+            .one(&self.db)
+            .await?
+            .unwrap_or_default();
+        let average_length = ((title_total.unwrap_or(0) * TITLE_WEIGHT as i64 + body_total.unwrap_or(0)) as f64
+            / document_count)
+            .max(1.0);
+
+        let idfs: HashMap<String, f64> = search_terms::Entity::find()
+            .select_only()
+            .column(search_terms::Column::Term)
+            .column_as(search_terms::Column::QuestionId.count(), "frequency")
+            .filter(search_terms::Column::Term.is_in(terms.clone()))
+            .group_by(search_terms::Column::Term)
+            .into_tuple::<(String, i64)>()
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|(term, frequency)| {
+                let frequency = frequency as f64;
+                (term, (1.0 + (document_count - frequency + 0.5) / (frequency + 0.5)).ln())
+            })
+            .collect();
+        if idfs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut match_score = CaseStatement::new();
+        for (term, idf) in &idfs {
+            match_score = match_score.case(search_terms::Column::Term.eq(term.as_str()), *idf);
+        }
+        let candidates: Vec<i32> = search_terms::Entity::find()
+            .select_only()
+            .column(search_terms::Column::QuestionId)
+            .filter(search_terms::Column::Term.is_in(terms.clone()))
+            .group_by(search_terms::Column::QuestionId)
+            .order_by(SimpleExpr::from(Func::sum(match_score.finally(0.0))), Order::Desc)
+            .order_by_desc(search_terms::Column::QuestionId)
+            .limit(CANDIDATE_DOCUMENTS)
+            .into_tuple::<i32>()
+            .all(&self.db)
+            .await?;
+
+        let postings = search_terms::Entity::find()
+            .filter(search_terms::Column::Term.is_in(terms))
+            .filter(search_terms::Column::QuestionId.is_in(candidates.clone()))
+            .all(&self.db)
+            .await?;
+
+        let lengths: HashMap<i32, f64> = search_documents::Entity::find()
+            .filter(search_documents::Column::QuestionId.is_in(candidates))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|document| {
+                (document.question_id, (document.title_length * TITLE_WEIGHT + document.body_length) as f64)
+            })
+            .collect();
+
+        let mut scores: HashMap<i32, f64> = HashMap::new();
+        for posting in &postings {
+            let idf = idfs.get(&posting.term).copied().unwrap_or(0.0);
+            let tf = (posting.title_frequency * TITLE_WEIGHT + posting.body_frequency) as f64;
+            let length = lengths.get(&posting.question_id).copied().unwrap_or(average_length);
+            let norm = K1 * (1.0 - B + B * length / average_length);
+
+            *scores.entry(posting.question_id).or_insert(0.0) += idf * tf * (K1 + 1.0) / (tf + norm);
+        }
+
+        let mut scored: Vec<(i32, f64)> = scores.into_iter().collect();
+        Self::rank(&mut scored);
+        scored.truncate(SIMILAR_CANDIDATES);
+
+        let mut canonical: Vec<String> = Vec::new();
+        for tag_name in tag_names {
+            let tag_name = TagRepository::canonical_name(&self.db, &normalize_tag_name(&tag_name)).await?;
+            if !tag_name.is_empty() && !canonical.contains(&tag_name) {
+                canonical.push(tag_name);
+            }
+        }
+
+        if !canonical.is_empty() {
+            let tag_count = canonical.len() as f64;
+            let shared: HashMap<i32, i64> = question_tags::Entity::find()
+                .select_only()
+                .column(question_tags::Column::QuestionId)
+                .column_as(question_tags::Column::TagId.count(), "shared")
+                .inner_join(tags::Entity)
+                .filter(tags::Column::Name.is_in(canonical))
+                .filter(question_tags::Column::QuestionId.is_in(scored.iter().map(|(id, _)| *id)))
+                .group_by(question_tags::Column::QuestionId)
+                .into_tuple::<(i32, i64)>()
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .collect();
+
+            for (question_id, score) in scored.iter_mut() {
+                if let Some(count) = shared.get(question_id) {
+                    *score += TAG_WEIGHT * *count as f64 / tag_count;
+                }
+            }
+            Self::rank(&mut scored);
+        }
+
+        scored.truncate(SIMILAR_LIMIT);
+
+        let ids: Vec<i32> = scored.iter().map(|(id, _)| *id).collect();
+        let mut questions: HashMap<i32, questions::Model> = questions::Entity::find()
+            .filter(questions::Column::Id.is_in(ids.clone()))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|question| (question.id, question))
+            .collect();
+
+        let answer_counts: HashMap<i32, i64> = answers::Entity::find()
+            .select_only()
+            .column(answers::Column::QuestionId)
+            .column_as(answers::Column::Id.count(), "count")
+            .filter(answers::Column::QuestionId.is_in(ids))
+            .group_by(answers::Column::QuestionId)
+            .into_tuple::<(i32, i64)>()
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(scored
+            .into_iter()
+            .filter_map(|(id, score)| {
+                questions.remove(&id).map(|question| SimilarQuestion {
+                    id,
+                    title: question.title,
+                    score,
+                    answer_count: answer_counts.get(&id).copied().unwrap_or(0) as i32,
+                    is_answered: question.is_answered != 0,
+                    is_closed: question.is_closed != 0,
+                })
+            })
+            .collect())
+    }
+
+    pub async fn index_question<C: ConnectionTrait>(
+        conn: &C,
+        question_id: i32,
+        title: &str,
+        content: &str,
+    ) -> Result<()> {
+        let (title_counts, title_length) = Self::term_counts(title);
+        let (body_counts, body_length) = Self::term_counts(content);
+
+        search_documents::Entity::insert(search_documents::ActiveModel {
+            question_id: Set(question_id),
+            title_length: Set(title_length),
+            body_length: Set(body_length),
+            indexed_at: Set(chrono::Utc::now()),
+        })
+        .on_conflict(
+            OnConflict::column(search_documents::Column::QuestionId)
+                .update_columns([
+                    search_documents::Column::TitleLength,
+                    search_documents::Column::BodyLength,
+                    search_documents::Column::IndexedAt,
+                ])
+                .to_owned(),
+        )
+        .exec(conn)
+        .await?;
+
+        search_terms::Entity::delete_many()
+            .filter(search_terms::Column::QuestionId.eq(question_id))
+            .exec(conn)
+            .await?;
+
+        let mut frequencies: HashMap<String, (i32, i32)> = HashMap::new();
+        for (term, count) in title_counts {
+            frequencies.entry(term).or_insert((0, 0)).0 += count;
+        }
+        for (term, count) in body_counts {
+            frequencies.entry(term).or_insert((0, 0)).1 += count;
+        }
+
+        if !frequencies.is_empty() {
+            search_terms::Entity::insert_many(frequencies.into_iter().map(|(term, (title_frequency, body_frequency))| {
+                search_terms::ActiveModel {
+                    question_id: Set(question_id),
+                    term: Set(term),
+                    title_frequency: Set(title_frequency),
+                    body_frequency: Set(body_frequency),
+                }
+            }))
+            .exec(conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn index_missing(&self) -> Result<u64> {
+        let mut indexed = 0;
+
+        loop {
+            let indexed_ids = search_documents::Entity::find()
+                .select_only()
+                .column(search_documents::Column::QuestionId)
+                .into_query();
+
+            let batch = questions::Entity::find()
+                .filter(questions::Column::Id.not_in_subquery(indexed_ids))
+                .order_by_asc(questions::Column::Id)
+                .limit(INDEX_BATCH)
+                .all(&self.db)
+                .await?;
+
+            if batch.is_empty() {
+                return Ok(indexed);
+            }
+
+            let txn = self.db.begin().await?;
+            for question in &batch {
+                Self::index_question(&txn, question.id, &question.title, &question.content).await?;
+            }
+            txn.commit().await?;
+
+            indexed += batch.len() as u64;
+        }
+    }
+
+    fn term_counts(value: &str) -> (HashMap<String, i32>, i32) {
+        let mut counts = HashMap::new();
+        let mut length = 0;
+
+        for term in text::tokenize(value) {
+            if term.len() > MAX_TERM_LENGTH {
+                continue;
+            }
+            length += 1;
+            *counts.entry(term).or_insert(0) += 1;
+        }
+
+        (counts, length)
+    }
+
+    fn rank(scored: &mut [(i32, f64)]) {
+        scored.sort_by(|(a_id, a), (b_id, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal).then(b_id.cmp(a_id)));
+    }
+}
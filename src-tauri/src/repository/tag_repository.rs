@@ -1,8 +1,23 @@
 use sea_orm::*;
-use crate::entities::{tags, question_tags};
-use crate::models::tag_models::TagResponse;
+use sea_orm::sea_query::{Expr, OnConflict};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use crate::config::TagConfig;
+use crate::entities::{
+    answers, question_tags, questions, tag_audit_logs, tag_preferences, tag_revisions, tag_synonyms, tags, users,
+};
+use crate::entities::sea_orm_active_enums::{SynonymStatus, TagAuditAction};
+use crate::models::tag_models::{
+    EditTagWikiRequest, TagAuditLogResponse, TagResponse, TagRevisionResponse, TagSuggestion, TagWikiResponse,
+    TopAnswerer, UpdateTagRequest, UserBrief,
+};
+use crate::models::validation::normalize_tag_name;
 use crate::error::{Result, AppError};
 
+const TOP_ANSWERERS: usize = 5;
+const AUTOCOMPLETE_LIMIT: u64 = 10;
+const MAX_AUTOCOMPLETE_LIMIT: u64 = 25;
+
 pub struct TagRepository {
     db: DatabaseConnection,
 }
@@ -12,81 +27,718 @@ impl TagRepository {
         Self { db }
     }
 
-    pub async fn create_tag(&self, name: String, description: Option<String>) -> Result<TagResponse> {
+    pub async fn create_tag(
+        &self,
+        user_id: i32,
+        name: String,
+        excerpt: Option<String>,
+        wiki: Option<String>,
+        config: &TagConfig,
+    ) -> Result<TagResponse> {
+        Self::ensure_can_create(&self.db, user_id, config).await?;
+
+        let name = normalize_tag_name(&name);
+        Self::ensure_not_synonym(&self.db, &name).await?;
+
+        let taken = tags::Entity::find()
+            .filter(tags::Column::Name.eq(&name))
+            .count(&self.db)
+            .await? > 0;
+
+        if taken {
+            return Err(AppError::Conflict(format!("A tag named '{}' already exists", name)));
+        }
+
+        let txn = self.db.begin().await?;
+
         let tag = tags::ActiveModel {
             name: Set(name),
-            description: Set(description),
             ..Default::default()
-        }.insert(&self.db).await?;
+        }.insert(&txn).await?;
+
+        Self::save_revision(&txn, user_id, &tag, excerpt.and_then(clean), wiki.and_then(clean), None).await?;
+
+        txn.commit().await?;
 
         self.get_tag_by_id(tag.id).await
     }
 
     pub async fn get_tag_by_id(&self, id: i32) -> Result<TagResponse> {
-        let tag = tags::Entity::find_by_id(id)
-            .one(&self.db)
+        let tag = Self::find_tag(&self.db, id).await?;
+
+        self.to_responses(vec![tag])
             .await?
-            .ok_or_else(|| AppError::NotFound("Tag not found".to_string()))?;
+            .pop()
+            .ok_or_else(|| AppError::NotFound("Tag not found".to_string()))
+    }
 
-        let question_count = question_tags::Entity::find()
-            .filter(question_tags::Column::TagId.eq(tag.id))
-            .count(&self.db)
+    pub async fn list_tags(&self) -> Result<Vec<TagResponse>> {
+        let tags = tags::Entity::find()
+            .order_by_asc(tags::Column::Name)
+            .all(&self.db)
             .await?;
 
-        Ok(TagResponse {
-            id: tag.id,
-            name: tag.name,
-            description: tag.description,
-            question_count: question_count as i32,
-        })
+        self.to_responses(tags).await
     }
 
-    pub async fn list_tags(&self) -> Result<Vec<TagResponse>> {
+    pub async fn search_tags(&self, query: &str) -> Result<Vec<TagResponse>> {
+        let query = normalize_tag_name(query);
         let tags = tags::Entity::find()
+            .filter(tags::Column::Name.contains(&query))
             .order_by_asc(tags::Column::Name)
             .all(&self.db)
             .await?;
 
-        let mut responses = Vec::new();
-        for tag in tags {
-            let question_count = question_tags::Entity::find()
-                .filter(question_tags::Column::TagId.eq(tag.id))
-                .count(&self.db)
+        self.to_responses(tags).await
+    }
+
+    pub async fn autocomplete(
+        &self,
+        term: &str,
+        limit: Option<u64>,
+        exclude: Vec<String>,
+    ) -> Result<Vec<TagSuggestion>> {
+        let term: String = normalize_tag_name(term)
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '+' | '#'))
+            .collect();
+        if term.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let limit = limit.unwrap_or(AUTOCOMPLETE_LIMIT).clamp(1, MAX_AUTOCOMPLETE_LIMIT);
+        let exclude: Vec<String> = exclude.iter().map(|name| normalize_tag_name(name)).collect();
+
+        let mut suggestions: Vec<TagSuggestion> = Vec::new();
+        for pattern in [tags::Column::Name.starts_with(&term), tags::Column::Name.contains(&term)] {
+            let remaining = limit.saturating_sub(suggestions.len() as u64);
+            if remaining == 0 {
+                break;
+            }
+
+            let found: Vec<i32> = suggestions.iter().map(|s| s.id).collect();
+            let matches = tags::Entity::find()
+                .select_only()
+                .column(tags::Column::Id)
+                .column(tags::Column::Name)
+                .column(tags::Column::Excerpt)
+                .column_as(question_tags::Column::QuestionId.count(), "uses")
+                .join(JoinType::LeftJoin, tags::Relation::QuestionTags.def())
+                .filter(pattern)
+                .filter(tags::Column::Id.is_not_in(found))
+                .filter(tags::Column::Name.is_not_in(exclude.clone()))
+                .group_by(tags::Column::Id)
+                .order_by(question_tags::Column::QuestionId.count(), Order::Desc)
+                .order_by_asc(tags::Column::Name)
+                .limit(remaining)
+                .into_tuple::<(i32, String, Option<String>, i64)>()
+                .all(&self.db)
                 .await?;
 
-            responses.push(TagResponse {
-                id: tag.id,
-                name: tag.name,
-                description: tag.description,
-                question_count: question_count as i32,
-            });
+            suggestions.extend(matches.into_iter().map(|(id, name, excerpt, uses)| TagSuggestion {
+                id,
+                name,
+                excerpt,
+                question_count: uses as i32,
+                synonym: None,
+            }));
         }
 
-        Ok(responses)
+        for pattern in [
+            tag_synonyms::Column::SourceName.starts_with(&term),
+            tag_synonyms::Column::SourceName.contains(&term),
+        ] {
+            let remaining = limit.saturating_sub(suggestions.len() as u64);
+            if remaining == 0 {
+                break;
+            }
+
+            let found: Vec<i32> = suggestions.iter().map(|s| s.id).collect();
+            let uses = Expr::col((question_tags::Entity, question_tags::Column::QuestionId)).count_distinct();
+            let synonyms = tag_synonyms::Entity::find()
+                .select_only()
+                .column(tags::Column::Id)
+                .column(tags::Column::Name)
+                .column(tags::Column::Excerpt)
+                .column_as(uses.clone(), "uses")
+                .column_as(Expr::col((tag_synonyms::Entity, tag_synonyms::Column::SourceName)).min(), "synonym")
+                .join(JoinType::InnerJoin, tag_synonyms::Relation::Tags.def())
+                .join(JoinType::LeftJoin, tags::Relation::QuestionTags.def())
+                .filter(tag_synonyms::Column::Status.eq(SynonymStatus::Approved))
+                .filter(pattern)
+                .filter(tags::Column::Id.is_not_in(found))
+                .filter(tags::Column::Name.is_not_in(exclude.clone()))
+                .group_by(tags::Column::Id)
+                .order_by(uses, Order::Desc)
+                .order_by_asc(tags::Column::Name)
+                .limit(remaining)
+                .into_tuple::<(i32, String, Option<String>, i64, String)>()
+                .all(&self.db)
+                .await?;
+
+            suggestions.extend(synonyms.into_iter().map(|(id, name, excerpt, uses, synonym)| TagSuggestion {
+                id,
+                name,
+                excerpt,
+                question_count: uses as i32,
+                synonym: Some(synonym),
+            }));
+        }
+
+        Ok(suggestions)
     }
 
-    pub async fn search_tags(&self, query: &str) -> Result<Vec<TagResponse>> {
-        let tags = tags::Entity::find()
-            .filter(tags::Column::Name.contains(query))
-            .order_by_asc(tags::Column::Name)
+    pub async fn get_wiki(&self, tag_id: i32) -> Result<TagWikiResponse> {
+        let tag = Self::find_tag(&self.db, tag_id).await?;
+
+        let latest = tag_revisions::Entity::find()
+            .filter(tag_revisions::Column::TagId.eq(tag_id))
+            .order_by_desc(tag_revisions::Column::Revision)
+            .one(&self.db)
+            .await?;
+
+        let editor_ids: HashSet<i32> = latest.iter().filter_map(|r| r.user_id).collect();
+        let editors = self.load_users(editor_ids).await?;
+
+        Ok(TagWikiResponse {
+            tag_id: tag.id,
+            name: tag.name,
+            excerpt: tag.excerpt,
+            wiki: tag.wiki,
+            revision: latest.as_ref().map(|r| r.revision).unwrap_or(0),
+            last_edited_by: latest
+                .as_ref()
+                .and_then(|r| r.user_id)
+                .and_then(|id| editors.get(&id).cloned()),
+            last_edited_at: latest.map(|r| r.created_at),
+        })
+    }
+
+    pub async fn get_revisions(&self, tag_id: i32) -> Result<Vec<TagRevisionResponse>> {
+        Self::find_tag(&self.db, tag_id).await?;
+
+        let revisions = tag_revisions::Entity::find()
+            .filter(tag_revisions::Column::TagId.eq(tag_id))
+            .order_by_desc(tag_revisions::Column::Revision)
             .all(&self.db)
             .await?;
 
-        let mut responses = Vec::new();
-        for tag in tags {
-            let question_count = question_tags::Entity::find()
-                .filter(question_tags::Column::TagId.eq(tag.id))
-                .count(&self.db)
+        let editor_ids: HashSet<i32> = revisions.iter().filter_map(|r| r.user_id).collect();
+        let editors = self.load_users(editor_ids).await?;
+
+        Ok(revisions
+            .into_iter()
+            .map(|revision| TagRevisionResponse {
+                revision: revision.revision,
+                excerpt: revision.excerpt,
+                wiki: revision.wiki,
+                comment: revision.comment,
+                editor: revision.user_id.and_then(|id| editors.get(&id).cloned()),
+                created_at: revision.created_at,
+            })
+            .collect())
+    }
+
+    pub async fn edit_wiki(
+        &self,
+        user_id: i32,
+        tag_id: i32,
+        request: EditTagWikiRequest,
+        config: &TagConfig,
+    ) -> Result<TagWikiResponse> {
+        Self::ensure_reputation(
+            &self.db,
+            user_id,
+            config.wiki_edit_min_reputation,
+            "edit tag wikis",
+        ).await?;
+
+        let txn = self.db.begin().await?;
+
+        let tag = Self::find_tag(&txn, tag_id).await?;
+        let excerpt = match request.excerpt {
+            Some(excerpt) => clean(excerpt),
+            None => tag.excerpt.clone(),
+        };
+        let wiki = match request.wiki {
+            Some(wiki) => clean(wiki),
+            None => tag.wiki.clone(),
+        };
+
+        let changed = Self::save_revision(&txn, user_id, &tag, excerpt, wiki, request.comment.and_then(clean)).await?;
+        if !changed {
+            return Err(AppError::validation("wiki", "The edit does not change the excerpt or wiki"));
+        }
+
+        txn.commit().await?;
+
+        self.get_wiki(tag_id).await
+    }
+
+    pub async fn update_tag(&self, moderator_id: i32, id: i32, request: UpdateTagRequest) -> Result<TagResponse> {
+        let txn = self.db.begin().await?;
+
+        let tag = Self::find_tag(&txn, id).await?;
+
+        if let Some(name) = request.name.map(|name| normalize_tag_name(&name)).filter(|name| *name != tag.name) {
+            let taken = tags::Entity::find()
+                .filter(tags::Column::Name.eq(&name))
+                .count(&txn)
+                .await? > 0;
+
+            if taken {
+                return Err(AppError::Conflict(format!(
+                    "A tag named '{}' already exists; merge the tags instead",
+                    name
+                )));
+            }
+
+            tag_synonyms::Entity::delete_many()
+                .filter(tag_synonyms::Column::SourceName.eq(&name))
+                .filter(tag_synonyms::Column::TargetTagId.eq(id))
+                .exec(&txn)
                 .await?;
 
-            responses.push(TagResponse {
+            Self::ensure_not_synonym(&txn, &name).await?;
+
+            tags::ActiveModel {
+                id: Set(id),
+                name: Set(name.clone()),
+                ..Default::default()
+            }.update(&txn).await?;
+
+            Self::add_synonym(&txn, Some(moderator_id), &tag.name, id).await?;
+
+            Self::record(&txn, Some(moderator_id), TagAuditAction::Rename, &tag, None, Some(tag.name.clone()), Some(name)).await?;
+        }
+
+        if let Some(excerpt) = request.excerpt {
+            let excerpt = clean(excerpt);
+
+            if Self::save_revision(&txn, moderator_id, &tag, excerpt.clone(), tag.wiki.clone(), None).await? {
+                Self::record(&txn, Some(moderator_id), TagAuditAction::Update, &tag, None, tag.excerpt.clone(), excerpt).await?;
+            }
+        }
+
+        txn.commit().await?;
+
+        self.get_tag_by_id(id).await
+    }
+
+    pub async fn merge_tags(&self, moderator_id: i32, source_id: i32, target_id: i32) -> Result<TagResponse> {
+        if source_id == target_id {
+            return Err(AppError::validation("target_id", "A tag cannot be merged into itself"));
+        }
+
+        let txn = self.db.begin().await?;
+
+        let source = Self::find_tag(&txn, source_id).await?;
+        let target = Self::find_tag(&txn, target_id).await?;
+
+        Self::merge_into(&txn, Some(moderator_id), &source, &target).await?;
+
+        txn.commit().await?;
+
+        self.get_tag_by_id(target_id).await
+    }
+
+    pub async fn merge_into<C: ConnectionTrait>(
+        conn: &C,
+        moderator_id: Option<i32>,
+        source: &tags::Model,
+        target: &tags::Model,
+    ) -> Result<()> {
+        let tagged: Vec<i32> = question_tags::Entity::find()
+            .select_only()
+            .column(question_tags::Column::QuestionId)
+            .filter(question_tags::Column::TagId.eq(target.id))
+            .into_tuple::<i32>()
+            .all(conn)
+            .await?;
+
+        question_tags::Entity::delete_many()
+            .filter(question_tags::Column::TagId.eq(source.id))
+            .filter(question_tags::Column::QuestionId.is_in(tagged))
+            .exec(conn)
+            .await?;
+
+        let moved = question_tags::Entity::update_many()
+            .col_expr(question_tags::Column::TagId, target.id.into())
+            .filter(question_tags::Column::TagId.eq(source.id))
+            .exec(conn)
+            .await?;
+
+        let preferring: Vec<i32> = tag_preferences::Entity::find()
+            .select_only()
+            .column(tag_preferences::Column::UserId)
+            .filter(tag_preferences::Column::TagId.eq(target.id))
+            .into_tuple::<i32>()
+            .all(conn)
+            .await?;
+
+        tag_preferences::Entity::delete_many()
+            .filter(tag_preferences::Column::TagId.eq(source.id))
+            .filter(tag_preferences::Column::UserId.is_in(preferring))
+            .exec(conn)
+            .await?;
+
+        tag_preferences::Entity::update_many()
+            .col_expr(tag_preferences::Column::TagId, target.id.into())
+            .filter(tag_preferences::Column::TagId.eq(source.id))
+            .exec(conn)
+            .await?;
+
+        tag_synonyms::Entity::update_many()
+            .col_expr(tag_synonyms::Column::TargetTagId, target.id.into())
+            .filter(tag_synonyms::Column::TargetTagId.eq(source.id))
+            .exec(conn)
+            .await?;
+
+        tag_synonyms::Entity::delete_many()
+            .filter(tag_synonyms::Column::SourceName.eq(&target.name))
+            .exec(conn)
+            .await?;
+
+        Self::add_synonym(conn, moderator_id, &source.name, target.id).await?;
+
+        tags::Entity::delete_by_id(source.id).exec(conn).await?;
+
+        Self::record(
+            conn,
+            moderator_id,
+            TagAuditAction::Merge,
+            source,
+            Some(target.id),
+            Some(source.name.clone()),
+            Some(format!("{} ({} questions moved)", target.name, moved.rows_affected)),
+        ).await
+    }
+
+    pub async fn canonical_name<C: ConnectionTrait>(conn: &C, name: &str) -> Result<String> {
+        let master = tag_synonyms::Entity::find()
+            .filter(tag_synonyms::Column::SourceName.eq(name))
+            .filter(tag_synonyms::Column::Status.eq(SynonymStatus::Approved))
+            .find_also_related(tags::Entity)
+            .one(conn)
+            .await?;
+
+        Ok(match master {
+            Some((_, Some(tag))) => tag.name,
+            _ => name.to_string(),
+        })
+    }
+
+    pub async fn ensure_can_create<C: ConnectionTrait>(conn: &C, user_id: i32, config: &TagConfig) -> Result<()> {
+        Self::ensure_reputation(
+            conn,
+            user_id,
+            config.create_min_reputation,
+            "create new tags; pick an existing tag instead",
+        ).await
+    }
+
+    async fn ensure_reputation<C: ConnectionTrait>(conn: &C, user_id: i32, min_reputation: i32, action: &str) -> Result<()> {
+        let user = users::Entity::find_by_id(user_id)
+            .one(conn)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        if user.reputation < min_reputation && !user.is_moderator {
+            return Err(AppError::Forbidden(format!(
+                "You need at least {} reputation to {}",
+                min_reputation, action
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn save_revision<C: ConnectionTrait>(
+        conn: &C,
+        user_id: i32,
+        tag: &tags::Model,
+        excerpt: Option<String>,
+        wiki: Option<String>,
+        comment: Option<String>,
+    ) -> Result<bool> {
+        if excerpt == tag.excerpt && wiki == tag.wiki {
+            return Ok(false);
+        }
+
+        tags::ActiveModel {
+            id: Set(tag.id),
+            excerpt: Set(excerpt.clone()),
+            wiki: Set(wiki.clone()),
+            ..Default::default()
+        }.update(conn).await?;
+
+        let latest: Option<i32> = tag_revisions::Entity::find()
+            .select_only()
+            .column_as(tag_revisions::Column::Revision.max(), "revision")
+            .filter(tag_revisions::Column::TagId.eq(tag.id))
+            .into_tuple::<Option<i32>>()
+            .one(conn)
+            .await?
+            .flatten();
+
+        tag_revisions::ActiveModel {
+            tag_id: Set(tag.id),
+            user_id: Set(Some(user_id)),
+            revision: Set(latest.unwrap_or(0) + 1),
+            excerpt: Set(excerpt),
+            wiki: Set(wiki),
+            comment: Set(comment),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }.insert(conn).await?;
+
+        Ok(true)
+    }
+
+    async fn to_responses(&self, tags: Vec<tags::Model>) -> Result<Vec<TagResponse>> {
+        let ids: Vec<i32> = tags.iter().map(|tag| tag.id).collect();
+        let now = chrono::Utc::now();
+
+        let totals = self.count_questions(&ids, None).await?;
+        let this_week = self.count_questions(&ids, Some(now - chrono::Duration::days(7))).await?;
+        let this_month = self.count_questions(&ids, Some(now - chrono::Duration::days(30))).await?;
+
+        let answer_counts = question_tags::Entity::find()
+            .select_only()
+            .column(question_tags::Column::TagId)
+            .column(answers::Column::UserId)
+            .column_as(answers::Column::Id.count(), "count")
+            .join(JoinType::InnerJoin, question_tags::Relation::Questions.def())
+            .join(JoinType::InnerJoin, questions::Relation::Answers.def())
+            .filter(question_tags::Column::TagId.is_in(ids))
+            .group_by(question_tags::Column::TagId)
+            .group_by(answers::Column::UserId)
+            .into_tuple::<(i32, i32, i64)>()
+            .all(&self.db)
+            .await?;
+
+        let mut answerers: HashMap<i32, Vec<(i32, i64)>> = HashMap::new();
+        for (tag_id, user_id, count) in answer_counts {
+            answerers.entry(tag_id).or_default().push((user_id, count));
+        }
+        for counts in answerers.values_mut() {
+            counts.sort_by_key(|&(user_id, count)| (Reverse(count), user_id));
+            counts.truncate(TOP_ANSWERERS);
+        }
+
+        let user_ids: HashSet<i32> = answerers.values().flatten().map(|&(user_id, _)| user_id).collect();
+        let users = self.load_users(user_ids).await?;
+
+        Ok(tags
+            .into_iter()
+            .map(|tag| TagResponse {
                 id: tag.id,
+                question_count: totals.get(&tag.id).copied().unwrap_or(0) as i32,
+                questions_this_week: this_week.get(&tag.id).copied().unwrap_or(0) as i32,
+                questions_this_month: this_month.get(&tag.id).copied().unwrap_or(0) as i32,
+                top_answerers: answerers
+                    .get(&tag.id)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|(user_id, count)| {
+                        users.get(user_id).map(|user| TopAnswerer {
+                            user: user.clone(),
+                            answer_count: *count as i32,
+                        })
+                    })
+                    .collect(),
                 name: tag.name,
-                description: tag.description,
-                question_count: question_count as i32,
-            });
+                excerpt: tag.excerpt,
+            })
+            .collect())
+    }
+
+    async fn count_questions(
+        &self,
+        ids: &[i32],
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<HashMap<i32, i64>> {
+        let mut query = question_tags::Entity::find()
+            .select_only()
+            .column(question_tags::Column::TagId)
+            .column_as(question_tags::Column::QuestionId.count(), "count")
+            .filter(question_tags::Column::TagId.is_in(ids.to_vec()))
+            .group_by(question_tags::Column::TagId);
+
+        if let Some(since) = since {
+            query = query
+                .join(JoinType::InnerJoin, question_tags::Relation::Questions.def())
+                .filter(questions::Column::CreatedAt.gte(since));
         }
 
-        Ok(responses)
+        Ok(query
+            .into_tuple::<(i32, i64)>()
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .collect())
+    }
+
+    async fn load_users(&self, ids: HashSet<i32>) -> Result<HashMap<i32, UserBrief>> {
+        Ok(users::Entity::find()
+            .filter(users::Column::Id.is_in(ids))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|user| {
+                (user.id, UserBrief {
+                    id: user.id,
+                    username: user.username,
+                    display_name: user.display_name,
+                    reputation: user.reputation,
+                    avatar_url: user.avatar_url,
+                })
+            })
+            .collect())
     }
+
+    async fn add_synonym<C: ConnectionTrait>(
+        conn: &C,
+        moderator_id: Option<i32>,
+        source_name: &str,
+        target_id: i32,
+    ) -> Result<()> {
+        let now = chrono::Utc::now();
+        tag_synonyms::Entity::insert(tag_synonyms::ActiveModel {
+            source_name: Set(source_name.to_string()),
+            target_tag_id: Set(target_id),
+            suggested_by: Set(moderator_id),
+            status: Set(SynonymStatus::Approved),
+            score: Set(0),
+            reviewed_by: Set(moderator_id),
+            reviewed_at: Set(Some(now)),
+            created_at: Set(now),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::column(tag_synonyms::Column::SourceName)
+                .update_columns([
+                    tag_synonyms::Column::TargetTagId,
+                    tag_synonyms::Column::Status,
+                    tag_synonyms::Column::ReviewedBy,
+                    tag_synonyms::Column::ReviewedAt,
+                ])
+                .to_owned(),
+        )
+        .exec(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn ensure_not_synonym<C: ConnectionTrait>(conn: &C, name: &str) -> Result<()> {
+        let master = Self::canonical_name(conn, name).await?;
+
+        if master != name {
+            return Err(AppError::Conflict(format!(
+                "'{}' is a synonym of '{}'; use that tag instead",
+                name, master
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete_tag(&self, moderator_id: i32, id: i32) -> Result<()> {
+        let txn = self.db.begin().await?;
+
+        let tag = Self::find_tag(&txn, id).await?;
+
+        let question_count = question_tags::Entity::find()
+            .filter(question_tags::Column::TagId.eq(id))
+            .count(&txn)
+            .await?;
+
+        if question_count > 0 {
+            return Err(AppError::Conflict(format!(
+                "'{}' is used by {} questions; merge it into another tag instead",
+                tag.name, question_count
+            )));
+        }
+
+        tags::Entity::delete_by_id(id).exec(&txn).await?;
+
+        Self::record(&txn, Some(moderator_id), TagAuditAction::Delete, &tag, None, tag.excerpt.clone(), None).await?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn get_audit_log(&self, tag_id: Option<i32>, page: u64, per_page: u64) -> Result<Vec<TagAuditLogResponse>> {
+        let mut query = tag_audit_logs::Entity::find();
+
+        if let Some(tag_id) = tag_id {
+            query = query.filter(
+                Condition::any()
+                    .add(tag_audit_logs::Column::TagId.eq(tag_id))
+                    .add(tag_audit_logs::Column::TargetTagId.eq(tag_id)),
+            );
+        }
+
+        let entries = query
+            .order_by_desc(tag_audit_logs::Column::CreatedAt)
+            .order_by_desc(tag_audit_logs::Column::Id)
+            .paginate(&self.db, per_page.clamp(1, 100))
+            .fetch_page(page.max(1) - 1)
+            .await?;
+
+        let moderator_ids: HashSet<i32> = entries.iter().filter_map(|e| e.moderator_id).collect();
+        let moderators = self.load_users(moderator_ids).await?;
+
+        Ok(entries
+            .into_iter()
+            .map(|entry| TagAuditLogResponse {
+                id: entry.id,
+                action: entry.action,
+                moderator: entry.moderator_id.and_then(|id| moderators.get(&id).cloned()),
+                tag_id: entry.tag_id,
+                tag_name: entry.tag_name,
+                target_tag_id: entry.target_tag_id,
+                old_value: entry.old_value,
+                new_value: entry.new_value,
+                created_at: entry.created_at,
+            })
+            .collect())
+    }
+
+    async fn find_tag<C: ConnectionTrait>(conn: &C, id: i32) -> Result<tags::Model> {
+        tags::Entity::find_by_id(id)
+            .one(conn)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Tag not found".to_string()))
+    }
+
+    async fn record<C: ConnectionTrait>(
+        conn: &C,
+        moderator_id: Option<i32>,
+        action: TagAuditAction,
+        tag: &tags::Model,
+        target_tag_id: Option<i32>,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) -> Result<()> {
+        tag_audit_logs::ActiveModel {
+            moderator_id: Set(moderator_id),
+            action: Set(action),
+            tag_id: Set(tag.id),
+            tag_name: Set(tag.name.clone()),
+            target_tag_id: Set(target_tag_id),
+            old_value: Set(old_value),
+            new_value: Set(new_value),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }.insert(conn).await?;
+
+        Ok(())
+    }
+}
+
+fn clean(value: String) -> Option<String> {
+    Some(value.trim().to_string()).filter(|value| !value.is_empty())
 }
@@ -0,0 +1,290 @@
+use sea_orm::*;
+use sea_orm::sea_query::OnConflict;
+use std::collections::HashMap;
+use crate::config::TagConfig;
+use crate::entities::{tag_synonym_votes, tag_synonyms, tags, users};
+use crate::entities::sea_orm_active_enums::{SynonymStatus, VoteType};
+use crate::models::tag_synonym_models::TagSynonymResponse;
+use crate::models::validation::normalize_tag_name;
+use crate::repository::tag_repository::TagRepository;
+use crate::error::{AppError, Result};
+
+pub struct TagSynonymRepository {
+    db: DatabaseConnection,
+}
+
+impl TagSynonymRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn suggest(
+        &self,
+        user_id: i32,
+        source_name: String,
+        target_tag_id: i32,
+        config: &TagConfig,
+    ) -> Result<TagSynonymResponse> {
+        self.ensure_can_suggest(user_id, config).await?;
+
+        let source_name = normalize_tag_name(&source_name);
+        let target = tags::Entity::find_by_id(target_tag_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Tag not found".to_string()))?;
+
+        if source_name == target.name {
+            return Err(AppError::validation("source_name", "A tag cannot be a synonym of itself"));
+        }
+
+        let existing = tag_synonyms::Entity::find()
+            .filter(tag_synonyms::Column::SourceName.eq(&source_name))
+            .one(&self.db)
+            .await?;
+
+        if existing.is_some() {
+            return Err(AppError::Conflict(format!(
+                "A synonym for '{}' has already been suggested",
+                source_name
+            )));
+        }
+
+        let synonym = tag_synonyms::ActiveModel {
+            source_name: Set(source_name),
+            target_tag_id: Set(target.id),
+            suggested_by: Set(Some(user_id)),
+            status: Set(SynonymStatus::Pending),
+            score: Set(0),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }.insert(&self.db).await?;
+
+        self.get_synonym(synonym.id, Some(user_id)).await
+    }
+
+    pub async fn vote(
+        &self,
+        user_id: i32,
+        synonym_id: i32,
+        vote_type: VoteType,
+        config: &TagConfig,
+    ) -> Result<TagSynonymResponse> {
+        self.ensure_can_suggest(user_id, config).await?;
+
+        let txn = self.db.begin().await?;
+
+        let synonym = Self::find_pending(&txn, synonym_id).await?;
+
+        if synonym.suggested_by == Some(user_id) {
+            return Err(AppError::Forbidden("You cannot vote on your own suggestion".to_string()));
+        }
+
+        tag_synonym_votes::Entity::insert(tag_synonym_votes::ActiveModel {
+            synonym_id: Set(synonym_id),
+            user_id: Set(user_id),
+            vote_type: Set(vote_type),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::columns([
+                tag_synonym_votes::Column::SynonymId,
+                tag_synonym_votes::Column::UserId,
+            ])
+            .update_column(tag_synonym_votes::Column::VoteType)
+            .to_owned(),
+        )
+        .exec(&txn)
+        .await?;
+
+        let score = tag_synonym_votes::Entity::find()
+            .filter(tag_synonym_votes::Column::SynonymId.eq(synonym_id))
+            .all(&txn)
+            .await?
+            .iter()
+            .fold(0, |acc, vote| acc + vote.vote_type.score());
+
+        tag_synonyms::ActiveModel {
+            id: Set(synonym_id),
+            score: Set(score),
+            ..Default::default()
+        }.update(&txn).await?;
+
+        if score >= config.synonym_approval_score {
+            Self::approve(&txn, &synonym, None).await?;
+        } else if score <= -config.synonym_approval_score {
+            Self::close(&txn, synonym_id, SynonymStatus::Rejected, None).await?;
+        }
+
+        txn.commit().await?;
+
+        self.get_synonym(synonym_id, Some(user_id)).await
+    }
+
+    pub async fn review(&self, moderator_id: i32, synonym_id: i32, approve: bool) -> Result<TagSynonymResponse> {
+        let txn = self.db.begin().await?;
+
+        let synonym = Self::find_pending(&txn, synonym_id).await?;
+
+        if approve {
+            Self::approve(&txn, &synonym, Some(moderator_id)).await?;
+        } else {
+            Self::close(&txn, synonym_id, SynonymStatus::Rejected, Some(moderator_id)).await?;
+        }
+
+        txn.commit().await?;
+
+        self.get_synonym(synonym_id, Some(moderator_id)).await
+    }
+
+    pub async fn list_synonyms(
+        &self,
+        tag_id: Option<i32>,
+        status: Option<SynonymStatus>,
+        viewer_id: Option<i32>,
+    ) -> Result<Vec<TagSynonymResponse>> {
+        let mut query = tag_synonyms::Entity::find();
+
+        if let Some(tag_id) = tag_id {
+            query = query.filter(tag_synonyms::Column::TargetTagId.eq(tag_id));
+        }
+
+        if let Some(status) = status {
+            query = query.filter(tag_synonyms::Column::Status.eq(status));
+        }
+
+        let synonyms = query
+            .order_by_desc(tag_synonyms::Column::Score)
+            .order_by_asc(tag_synonyms::Column::SourceName)
+            .all(&self.db)
+            .await?;
+
+        self.to_responses(synonyms, viewer_id).await
+    }
+
+    async fn get_synonym(&self, id: i32, viewer_id: Option<i32>) -> Result<TagSynonymResponse> {
+        let synonym = tag_synonyms::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Tag synonym not found".to_string()))?;
+
+        self.to_responses(vec![synonym], viewer_id)
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::NotFound("Tag synonym not found".to_string()))
+    }
+
+    async fn approve(
+        txn: &DatabaseTransaction,
+        synonym: &tag_synonyms::Model,
+        reviewer_id: Option<i32>,
+    ) -> Result<()> {
+        Self::close(txn, synonym.id, SynonymStatus::Approved, reviewer_id).await?;
+
+        let target = tags::Entity::find_by_id(synonym.target_tag_id)
+            .one(txn)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Tag not found".to_string()))?;
+
+        let source = tags::Entity::find()
+            .filter(tags::Column::Name.eq(&synonym.source_name))
+            .one(txn)
+            .await?;
+
+        if let Some(source) = source {
+            TagRepository::merge_into(txn, reviewer_id, &source, &target).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn close(
+        txn: &DatabaseTransaction,
+        synonym_id: i32,
+        status: SynonymStatus,
+        reviewer_id: Option<i32>,
+    ) -> Result<()> {
+        tag_synonyms::ActiveModel {
+            id: Set(synonym_id),
+            status: Set(status),
+            reviewed_by: Set(reviewer_id),
+            reviewed_at: Set(Some(chrono::Utc::now())),
+            ..Default::default()
+        }.update(txn).await?;
+
+        Ok(())
+    }
+
+    async fn find_pending(txn: &DatabaseTransaction, synonym_id: i32) -> Result<tag_synonyms::Model> {
+        let synonym = tag_synonyms::Entity::find_by_id(synonym_id)
+            .one(txn)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Tag synonym not found".to_string()))?;
+
+        if synonym.status != SynonymStatus::Pending {
+            return Err(AppError::Conflict("This synonym suggestion has already been decided".to_string()));
+        }
+
+        Ok(synonym)
+    }
+
+    async fn ensure_can_suggest(&self, user_id: i32, config: &TagConfig) -> Result<()> {
+        let user = users::Entity::find_by_id(user_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        if user.reputation < config.synonym_min_reputation && !user.is_moderator {
+            return Err(AppError::Forbidden(format!(
+                "You need at least {} reputation to suggest or vote on tag synonyms",
+                config.synonym_min_reputation
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn to_responses(
+        &self,
+        synonyms: Vec<tag_synonyms::Model>,
+        viewer_id: Option<i32>,
+    ) -> Result<Vec<TagSynonymResponse>> {
+        let tag_ids: Vec<i32> = synonyms.iter().map(|s| s.target_tag_id).collect();
+        let tag_names: HashMap<i32, String> = tags::Entity::find()
+            .filter(tags::Column::Id.is_in(tag_ids))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|tag| (tag.id, tag.name))
+            .collect();
+
+        let mut user_votes = HashMap::new();
+        if let Some(viewer_id) = viewer_id {
+            let synonym_ids: Vec<i32> = synonyms.iter().map(|s| s.id).collect();
+            user_votes = tag_synonym_votes::Entity::find()
+                .filter(tag_synonym_votes::Column::UserId.eq(viewer_id))
+                .filter(tag_synonym_votes::Column::SynonymId.is_in(synonym_ids))
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .map(|vote| (vote.synonym_id, vote.vote_type))
+                .collect();
+        }
+
+        Ok(synonyms
+            .into_iter()
+            .map(|synonym| TagSynonymResponse {
+                id: synonym.id,
+                target_name: tag_names.get(&synonym.target_tag_id).cloned().unwrap_or_default(),
+                user_vote: user_votes.get(&synonym.id).copied(),
+                source_name: synonym.source_name,
+                target_tag_id: synonym.target_tag_id,
+                status: synonym.status,
+                score: synonym.score,
+                suggested_by: synonym.suggested_by,
+                reviewed_at: synonym.reviewed_at,
+                created_at: synonym.created_at,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,352 @@
+use chrono::{Duration, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter, Set,
+    TransactionTrait,
+};
+
+use crate::auth::password::PasswordConfig;
+use crate::auth::secrets::{hash_secret, random_secret};
+use crate::auth::totp;
+use crate::entities::recovery_codes;
+use crate::error::{AppError, Result};
+use crate::entities::users::{
+    ActiveModel as UserActiveModel, Column as UserColumn, Entity as Users, Model as UserModel,
+};
+use crate::models::two_factor_models::{RecoveryCodesResponse, TotpEnrollmentResponse};
+
+const RECOVERY_CODE_COUNT: usize = 10;
+const MAX_FAILED_ATTEMPTS: i32 = 5;
+const LOCKOUT_MINUTES: i64 = 15;
+
+pub struct TwoFactorRepository;
+
+impl TwoFactorRepository {
+    async fn find_user(db: &DatabaseConnection, user_id: i32) -> Result<UserModel> {
+        Users::find_by_id(user_id)
+            .one(db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))
+    }
+
+    pub async fn begin_enrollment(
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<TotpEnrollmentResponse> {
+        let user = Self::find_user(db, user_id).await?;
+
+        if user.totp_enabled_at.is_some() {
+            return Err(AppError::Conflict("Two-factor authentication is already enabled".to_string()));
+        }
+
+        let secret = totp::generate_secret();
+        let provisioning_uri = totp::provisioning_uri(&secret, &user.email)?;
+
+        let mut user_active: UserActiveModel = user.into();
+        user_active.totp_secret = Set(Some(secret.clone()));
+        user_active.update(db).await?;
+
+        Ok(TotpEnrollmentResponse {
+            secret,
+            provisioning_uri,
+        })
+    }
+
+    pub async fn confirm_enrollment(
+        db: &DatabaseConnection,
+        user_id: i32,
+        code: &str,
+    ) -> Result<RecoveryCodesResponse> {
+        let user = Self::find_user(db, user_id).await?;
+
+        if user.totp_enabled_at.is_some() {
+            return Err(AppError::Conflict("Two-factor authentication is already enabled".to_string()));
+        }
+
+        let secret = user
+            .totp_secret
+            .clone()
+            .ok_or_else(|| AppError::Conflict("Two-factor enrollment has not been started".to_string()))?;
+
+        let step = totp::verify_code(&secret, &user.email, code)?
+            .ok_or_else(|| AppError::validation("code", "Invalid authentication code"))?;
+
+        let txn = db.begin().await?;
+
+        let mut user_active: UserActiveModel = user.into();
+        user_active.totp_enabled_at = Set(Some(Utc::now()));
+        user_active.totp_last_step = Set(Some(step as i64));
+        user_active.update(&txn).await?;
+
+        recovery_codes::Entity::delete_many()
+            .filter(recovery_codes::Column::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+
+        let mut codes = Vec::with_capacity(RECOVERY_CODE_COUNT);
+        for _ in 0..RECOVERY_CODE_COUNT {
+            let raw = random_secret(5);
+            let code = format!("{}-{}", &raw[..5], &raw[5..]);
+
+            recovery_codes::ActiveModel {
+                user_id: Set(user_id),
+                code_hash: Set(hash_secret(&Self::normalize_recovery_code(&code))),
+                used_at: Set(None),
+                created_at: Set(Utc::now()),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await?;
+
+            codes.push(code);
+        }
+
+        txn.commit().await?;
+
+        Ok(RecoveryCodesResponse {
+            recovery_codes: codes,
+        })
+    }
+
+    pub async fn disable(
+        db: &DatabaseConnection,
+        passwords: &PasswordConfig,
+        user_id: i32,
+        password: &str,
+        code: &str,
+    ) -> Result<()> {
+        let user = Self::find_user(db, user_id).await?;
+
+        if user.totp_enabled_at.is_none() {
+            return Err(AppError::Conflict("Two-factor authentication is not enabled".to_string()));
+        }
+
+        passwords
+            .verify_password(&user.password_hash, password)
+            .map_err(|_| AppError::validation("password", "Password is incorrect"))?;
+        Self::verify_second_factor(db, &user, code).await?;
+
+        let txn = db.begin().await?;
+
+        let mut user_active: UserActiveModel = user.into();
+        user_active.totp_secret = Set(None);
+        user_active.totp_enabled_at = Set(None);
+        user_active.totp_last_step = Set(None);
+        user_active.update(&txn).await?;
+
+        recovery_codes::Entity::delete_many()
+            .filter(recovery_codes::Column::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+
+        Ok(txn.commit().await?)
+    }
+
+    pub async fn verify_second_factor(
+        db: &DatabaseConnection,
+        user: &UserModel,
+        code: &str,
+    ) -> Result<()> {
+        let secret = match (&user.totp_secret, user.totp_enabled_at) {
+            (Some(secret), Some(_)) => secret,
+            _ => return Err(AppError::Conflict("Two-factor authentication is not enabled".to_string())),
+        };
+
+        if user.two_factor_locked_until.is_some_and(|until| until > Utc::now()) {
+            return Err(AppError::RateLimited(
+                "Too many failed authentication attempts, try again later".to_string(),
+            ));
+        }
+
+        if let Some(step) = totp::verify_code(secret, &user.email, code)? {
+            let step = step as i64;
+            let accepted = Users::update_many()
+                .col_expr(UserColumn::TotpLastStep, Expr::value(step))
+                .filter(UserColumn::Id.eq(user.id))
+                .filter(
+                    Condition::any()
+                        .add(UserColumn::TotpLastStep.is_null())
+                        .add(UserColumn::TotpLastStep.lt(step)),
+                )
+                .exec(db)
+                .await?
+                .rows_affected
+                > 0;
+
+            if accepted {
+                return Self::reset_failures(db, user.id).await;
+            }
+
+            return Self::record_failure(db, user.id).await;
+        }
+
+        let consumed = recovery_codes::Entity::update_many()
+            .filter(recovery_codes::Column::UserId.eq(user.id))
+            .filter(recovery_codes::Column::CodeHash.eq(hash_secret(&Self::normalize_recovery_code(code))))
+            .filter(recovery_codes::Column::UsedAt.is_null())
+            .set(recovery_codes::ActiveModel {
+                used_at: Set(Some(Utc::now())),
+                ..Default::default()
+            })
+            .exec(db)
+            .await?
+            .rows_affected
+            > 0;
+
+        if consumed {
+            return Self::reset_failures(db, user.id).await;
+        }
+
+        Self::record_failure(db, user.id).await
+    }
+
+    async fn reset_failures(db: &DatabaseConnection, user_id: i32) -> Result<()> {
+        Users::update_many()
+            .filter(UserColumn::Id.eq(user_id))
+            .set(UserActiveModel {
+                two_factor_failed_attempts: Set(0),
+                two_factor_locked_until: Set(None),
+                ..Default::default()
+            })
+            .exec(db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn record_failure(db: &DatabaseConnection, user_id: i32) -> Result<()> {
+        let attempts = Expr::col(UserColumn::TwoFactorFailedAttempts).add(1);
+        let exhausted = Expr::expr(attempts.clone()).gte(MAX_FAILED_ATTEMPTS);
+        let locked_until = Utc::now() + Duration::minutes(LOCKOUT_MINUTES);
+
+        let updated = Users::update_many()
+            .col_expr(
+                UserColumn::TwoFactorFailedAttempts,
+                Expr::case(exhausted.clone(), 0).finally(attempts).into(),
+            )
+            .col_expr(
+                UserColumn::TwoFactorLockedUntil,
+                Expr::case(exhausted, locked_until)
+                    .finally(Expr::col(UserColumn::TwoFactorLockedUntil))
+                    .into(),
+            )
+            .filter(UserColumn::Id.eq(user_id))
+            .exec_with_returning(db)
+            .await?;
+
+        if updated.iter().any(|user| user.two_factor_locked_until.is_some_and(|until| until > Utc::now())) {
+            return Err(AppError::RateLimited(
+                "Too many failed authentication attempts, try again later".to_string(),
+            ));
+        }
+
+        Err(AppError::validation("code", "Invalid authentication code"))
+    }
+
+    fn normalize_recovery_code(code: &str) -> String {
+        code.chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{ConnectionTrait, Database, DbBackend, Schema};
+    use totp_rs::{Algorithm, Secret, TOTP};
+
+    const EMAIL: &str = "alice@example.com";
+    const RECOVERY_CODE: &str = "abcde-12345";
+
+    async fn setup() -> (DatabaseConnection, UserModel) {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let schema = Schema::new(DbBackend::Sqlite);
+        db.execute(db.get_database_backend().build(&schema.create_table_from_entity(Users)))
+            .await
+            .unwrap();
+        db.execute(db.get_database_backend().build(&schema.create_table_from_entity(recovery_codes::Entity)))
+            .await
+            .unwrap();
+
+        let user = UserActiveModel {
+            username: Set("alice".to_string()),
+            email: Set(EMAIL.to_string()),
+            password_hash: Set(String::new()),
+            display_name: Set("Alice".to_string()),
+            reputation: Set(0),
+            created_at: Set(Utc::now()),
+            is_active: Set(1),
+            totp_secret: Set(Some(totp::generate_secret())),
+            totp_enabled_at: Set(Some(Utc::now())),
+            two_factor_failed_attempts: Set(0),
+            is_moderator: Set(false),
+            hide_ignored_tags: Set(false),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        recovery_codes::ActiveModel {
+            user_id: Set(user.id),
+            code_hash: Set(hash_secret(&TwoFactorRepository::normalize_recovery_code(RECOVERY_CODE))),
+            created_at: Set(Utc::now()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        (db, user)
+    }
+
+    fn current_code(user: &UserModel) -> String {
+        let secret = Secret::Encoded(user.totp_secret.clone().unwrap()).to_bytes().unwrap();
+        TOTP::new(Algorithm::SHA1, 6, 1, 30, secret, None, EMAIL.to_string())
+            .unwrap()
+            .generate_current()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn rejects_a_replayed_totp_step() {
+        let (db, user) = setup().await;
+        let code = current_code(&user);
+
+        TwoFactorRepository::verify_second_factor(&db, &user, &code).await.unwrap();
+
+        let replayed = TwoFactorRepository::verify_second_factor(&db, &user, &code).await;
+        assert!(matches!(replayed, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn rejects_a_reused_recovery_code() {
+        let (db, user) = setup().await;
+
+        TwoFactorRepository::verify_second_factor(&db, &user, RECOVERY_CODE).await.unwrap();
+
+        let reused = TwoFactorRepository::verify_second_factor(&db, &user, RECOVERY_CODE).await;
+        assert!(matches!(reused, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn locks_out_after_max_failed_attempts() {
+        let (db, user) = setup().await;
+
+        for _ in 1..MAX_FAILED_ATTEMPTS {
+            let failed = TwoFactorRepository::verify_second_factor(&db, &user, "000000").await;
+            assert!(matches!(failed, Err(AppError::Validation(_))));
+        }
+
+        let locked = TwoFactorRepository::verify_second_factor(&db, &user, "000000").await;
+        assert!(matches!(locked, Err(AppError::RateLimited(_))));
+
+        let user = TwoFactorRepository::find_user(&db, user.id).await.unwrap();
+        assert_eq!(user.two_factor_failed_attempts, 0);
+
+        let valid = TwoFactorRepository::verify_second_factor(&db, &user, RECOVERY_CODE).await;
+        assert!(matches!(valid, Err(AppError::RateLimited(_))));
+    }
+}
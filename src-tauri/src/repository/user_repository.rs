@@ -1,29 +1,34 @@
-use argon2::{
-    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
-    Argon2,
-};
-use chrono::Utc;
-use rand_core::OsRng;
+use chrono::{Duration, Utc};
+use email_address::EmailAddress;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set,
+    TransactionTrait,
 };
 
+use crate::error::{AppError, Result};
+
+use crate::auth::password::PasswordConfig;
+use crate::auth::secrets::{hash_secret, random_secret};
+use crate::entities::email_verifications;
 use crate::entities::users::{
     self, ActiveModel as UserActiveModel, Entity as Users, Model as UserModel,
 };
+use crate::mailer::{EmailMessage, Mailer};
 use crate::models::user_models::{LoginRequest, RegisterRequest, UserResponse, UpdateProfileRequest, ChangePasswordRequest};
 
+const VERIFICATION_TOKEN_TTL_HOURS: i64 = 24;
+
 pub struct UserRepository;
 
 impl UserRepository {
-    pub async fn find_by_id(db: &DatabaseConnection, id: i32) -> Result<Option<UserModel>, DbErr> {
+    pub async fn find_by_id(db: &DatabaseConnection, id: i32) -> std::result::Result<Option<UserModel>, DbErr> {
         Users::find_by_id(id).one(db).await
     }
 
     pub async fn find_by_email(
         db: &DatabaseConnection,
         email: &str,
-    ) -> Result<Option<UserModel>, DbErr> {
+    ) -> std::result::Result<Option<UserModel>, DbErr> {
         Users::find()
             .filter(users::Column::Email.eq(email))
             .one(db)
@@ -33,7 +38,7 @@ impl UserRepository {
     pub async fn find_by_username(
         db: &DatabaseConnection,
         username: &str,
-    ) -> Result<Option<UserModel>, DbErr> {
+    ) -> std::result::Result<Option<UserModel>, DbErr> {
         Users::find()
             .filter(users::Column::Username.eq(username))
             .one(db)
@@ -42,37 +47,36 @@ impl UserRepository {
 
     pub async fn register(
         db: &DatabaseConnection,
+        mailer: &dyn Mailer,
+        passwords: &PasswordConfig,
         req: RegisterRequest,
-    ) -> Result<UserModel, String> {
+    ) -> Result<UserModel> {
+        let email = Self::normalize_email(&req.email)?;
+        passwords
+            .check_policy(&req.password, &req.username, &email)
+            .map_err(|e| AppError::validation("password", e))?;
+
         let username_exists = Self::find_by_username(db, &req.username)
-            .await
-            .map_err(|e| format!("Database error: {}", e))?
+            .await?
             .is_some();
 
         if username_exists {
-            return Err("Username already exists".to_string());
+            return Err(AppError::Conflict("Username already exists".to_string()));
         }
 
-        let email_exists = Self::find_by_email(db, &req.email)
-            .await
-            .map_err(|e| format!("Database error: {}", e))?
+        let email_exists = Self::find_by_email(db, &email)
+            .await?
             .is_some();
 
         if email_exists {
-            return Err("Email already exists".to_string());
+            return Err(AppError::Conflict("Email already exists".to_string()));
         }
 
-        let salt = SaltString::generate(&mut OsRng);
-        let argon2 = Argon2::default();
-
-        let password_hash = argon2
-            .hash_password(req.password.as_bytes(), &salt)
-            .map_err(|e| format!("Password hashing error: {}", e))?
-            .to_string();
+        let password_hash = passwords.hash_password(&req.password)?;
 
         let user = UserActiveModel {
             username: Set(req.username),
-            email: Set(req.email),
+            email: Set(email),
             password_hash: Set(password_hash),
             display_name: Set(req.display_name),
             bio: Set(None),
@@ -81,54 +85,176 @@ impl UserRepository {
             last_login: Set(None),
             avatar_url: Set(None),
             is_active: Set(1),
+            verified_at: Set(None),
             ..Default::default()
         };
 
-        user.insert(db)
-            .await
-            .map_err(|e| format!("Failed to insert user: {}", e))
+        let txn = db.begin().await?;
+        let user = user.insert(&txn).await?;
+        let token = Self::issue_verification_token(&txn, &user).await?;
+        txn.commit().await?;
+
+        if let Err(e) = Self::mail_verification(mailer, &user, &token) {
+            println!("Failed to send verification email to user {}: {}", user.id, e);
+        }
+
+        Ok(user)
     }
 
-    pub async fn login(db: &DatabaseConnection, req: LoginRequest) -> Result<UserModel, String> {
-        let user = Self::find_by_email(db, &req.email)
-            .await
-            .map_err(|e| format!("Database error: {}", e))?
-            .ok_or_else(|| "User not found".to_string())?;
+    pub async fn send_verification_email(
+        db: &DatabaseConnection,
+        mailer: &dyn Mailer,
+        user: &UserModel,
+    ) -> Result<()> {
+        if user.verified_at.is_some() {
+            return Err(AppError::Conflict("Email is already verified".to_string()));
+        }
 
-        let parsed_hash = PasswordHash::new(&user.password_hash)
-            .map_err(|e| format!("Failed to parse password hash: {}", e))?;
+        let token = Self::issue_verification_token(db, user).await?;
 
-        Argon2::default()
-            .verify_password(req.password.as_bytes(), &parsed_hash)
-            .map_err(|_| "Invalid password".to_string())?;
+        Self::mail_verification(mailer, user, &token).map_err(|e| {
+            println!("Failed to send verification email to user {}: {}", user.id, e);
+            AppError::Internal("Failed to send verification email".to_string())
+        })
+    }
 
-        let mut user_active: UserActiveModel = user.clone().into();
-        user_active.last_login = Set(Some(Utc::now().into()));
+    async fn issue_verification_token<C: ConnectionTrait>(conn: &C, user: &UserModel) -> Result<String> {
+        email_verifications::Entity::delete_many()
+            .filter(email_verifications::Column::UserId.eq(user.id))
+            .exec(conn)
+            .await?;
 
-        let user = user_active
-            .update(db)
-            .await
-            .map_err(|e| format!("Failed to update last login: {}", e))?;
+        let token = random_secret(32);
+        let now = Utc::now();
+
+        email_verifications::ActiveModel {
+            user_id: Set(user.id),
+            token_hash: Set(hash_secret(&token)),
+            expires_at: Set(now + Duration::hours(VERIFICATION_TOKEN_TTL_HOURS)),
+            created_at: Set(now),
+            ..Default::default()
+        }
+        .insert(conn)
+        .await?;
+
+        Ok(token)
+    }
+
+    fn mail_verification(mailer: &dyn Mailer, user: &UserModel, token: &str) -> std::result::Result<(), String> {
+        mailer.send(EmailMessage {
+            to: user.email.clone(),
+            subject: "Confirm your QueueOverflow email address".to_string(),
+            body: format!(
+                "Hi {},\n\nUse this code to confirm your email address: {}\n\nThe code expires in {} hours.",
+                user.display_name, token, VERIFICATION_TOKEN_TTL_HOURS
+            ),
+        })
+    }
+
+    pub async fn verify_email(db: &DatabaseConnection, token: &str) -> Result<UserModel> {
+        let verification = email_verifications::Entity::find()
+            .filter(email_verifications::Column::TokenHash.eq(hash_secret(token.trim())))
+            .one(db)
+            .await?
+            .ok_or_else(|| AppError::validation("token", "Invalid verification token"))?;
+
+        if verification.expires_at < Utc::now() {
+            return Err(AppError::validation("token", "Verification token has expired"));
+        }
+
+        let user = Users::find_by_id(verification.user_id)
+            .one(db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        let mut user_active: UserActiveModel = user.into();
+        user_active.verified_at = Set(Some(Utc::now()));
+
+        let user = user_active.update(db).await?;
+
+        email_verifications::Entity::delete_many()
+            .filter(email_verifications::Column::UserId.eq(user.id))
+            .exec(db)
+            .await?;
 
         Ok(user)
     }
 
+    pub async fn login(
+        db: &DatabaseConnection,
+        passwords: &PasswordConfig,
+        req: LoginRequest,
+    ) -> Result<UserModel> {
+        let email = req.email.trim().to_lowercase();
+        let user = Self::find_by_email(db, &email)
+            .await?
+            .ok_or_else(|| AppError::Unauthorized("Invalid email or password".to_string()))?;
+
+        passwords
+            .verify_password(&user.password_hash, &req.password)
+            .map_err(|_| AppError::Unauthorized("Invalid email or password".to_string()))?;
+
+        let user = if passwords.needs_rehash(&user.password_hash) {
+            Self::rehash_password(db, passwords, user, &req.password).await
+        } else {
+            user
+        };
+
+        if user.totp_enabled_at.is_some() {
+            return Ok(user);
+        }
+
+        Self::record_login(db, user).await
+    }
+
+    pub async fn record_login(db: &DatabaseConnection, user: UserModel) -> Result<UserModel> {
+        let mut user_active: UserActiveModel = user.into();
+        user_active.last_login = Set(Some(Utc::now().into()));
+
+        Ok(user_active.update(db).await?)
+    }
+
+    async fn rehash_password(
+        db: &DatabaseConnection,
+        passwords: &PasswordConfig,
+        user: UserModel,
+        password: &str,
+    ) -> UserModel {
+        let password_hash = match passwords.hash_password(password) {
+            Ok(hash) => hash,
+            Err(e) => {
+                println!("Failed to rehash password for user {}: {}", user.id, e);
+                return user;
+            }
+        };
+
+        let mut user_active: UserActiveModel = user.clone().into();
+        user_active.password_hash = Set(password_hash);
+
+        match user_active.update(db).await {
+            Ok(updated) => updated,
+            Err(e) => {
+                println!("Failed to store rehashed password for user {}: {}", user.id, e);
+                user
+            }
+        }
+    }
+
     pub async fn update_profile(
         db: &DatabaseConnection,
         user_id: i32,
         req: UpdateProfileRequest,
-    ) -> Result<UserModel, String> {
+    ) -> Result<UserModel> {
         let user = Users::find_by_id(user_id)
             .one(db)
-            .await
-            .map_err(|e| format!("Database error: {}", e))?
-            .ok_or_else(|| "User not found".to_string())?;
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
         let mut user_active: UserActiveModel = user.into();
 
         if let Some(display_name) = req.display_name {
             if display_name.trim().is_empty() {
-                return Err("Display name cannot be empty".to_string());
+                return Err(AppError::validation("display_name", "Display name cannot be empty"));
             }
             user_active.display_name = Set(display_name);
         }
@@ -141,57 +267,52 @@ impl UserRepository {
             user_active.avatar_url = Set(Some(avatar_url));
         }
 
-        user_active
-            .update(db)
-            .await
-            .map_err(|e| format!("Failed to update profile: {}", e))
+        Ok(user_active.update(db).await?)
     }
 
     pub async fn change_password(
         db: &DatabaseConnection,
+        passwords: &PasswordConfig,
         user_id: i32,
         req: ChangePasswordRequest,
-    ) -> Result<(), String> {
+    ) -> Result<()> {
         let user = Users::find_by_id(user_id)
             .one(db)
-            .await
-            .map_err(|e| format!("Database error: {}", e))?
-            .ok_or_else(|| "User not found".to_string())?;
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-        let parsed_hash = PasswordHash::new(&user.password_hash)
-            .map_err(|e| format!("Failed to parse password hash: {}", e))?;
+        passwords
+            .verify_password(&user.password_hash, &req.current_password)
+            .map_err(|_| AppError::validation("current_password", "Current password is incorrect"))?;
 
-        Argon2::default()
-            .verify_password(req.current_password.as_bytes(), &parsed_hash)
-            .map_err(|_| "Current password is incorrect".to_string())?;
-
-        if req.new_password.len() < 8 {
-            return Err("New password must be at least 8 characters long".to_string());
-        }
+        passwords
+            .check_policy(&req.new_password, &user.username, &user.email)
+            .map_err(|e| AppError::validation("new_password", e))?;
 
         if req.new_password != req.confirm_password {
-            return Err("New password and confirmation do not match".to_string());
+            return Err(AppError::validation("confirm_password", "New password and confirmation do not match"));
         }
 
-        let salt = SaltString::generate(&mut OsRng);
-        let argon2 = Argon2::default();
-
-        let password_hash = argon2
-            .hash_password(req.new_password.as_bytes(), &salt)
-            .map_err(|e| format!("Password hashing error: {}", e))?
-            .to_string();
+        let password_hash = passwords.hash_password(&req.new_password)?;
 
         let mut user_active: UserActiveModel = user.into();
         user_active.password_hash = Set(password_hash);
 
-        user_active
-            .update(db)
-            .await
-            .map_err(|e| format!("Failed to update password: {}", e))?;
+        user_active.update(db).await?;
 
         Ok(())
     }
 
+    fn normalize_email(email: &str) -> Result<String> {
+        let email = email.trim().to_lowercase();
+
+        if !EmailAddress::is_valid(&email) {
+            return Err(AppError::validation("email", "Email address is not valid"));
+        }
+
+        Ok(email)
+    }
+
     pub fn model_to_response(user: UserModel) -> UserResponse {
         UserResponse {
             id: user.id,
@@ -201,6 +322,9 @@ impl UserRepository {
             bio: user.bio,
             reputation: user.reputation,
             avatar_url: user.avatar_url,
+            email_verified: user.verified_at.is_some(),
+            two_factor_enabled: user.totp_enabled_at.is_some(),
+            is_moderator: user.is_moderator,
             created_at: user.created_at.to_string(),
         }
     }
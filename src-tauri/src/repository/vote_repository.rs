@@ -1,24 +1,54 @@
 use sea_orm::*;
 use crate::entities::{votes, users, questions, answers};
+use crate::entities::sea_orm_active_enums::{TargetType, VoteType};
 use crate::models::vote_models::{VoteResponse, VoteCount};
-use crate::error::Result;
+use crate::repository::notification_repository::NotificationRepository;
+use crate::events::{ActivityEvent, EventBus};
+use crate::error::{AppError, Result};
 
 pub struct VoteRepository {
     db: DatabaseConnection,
+    events: EventBus,
 }
 
 impl VoteRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: DatabaseConnection, events: EventBus) -> Self {
+        Self { db, events }
     }
 
     pub async fn create_vote(
         &self,
         user_id: i32,
         target_id: i32,
-        target_type: String,
-        vote_type: String,
+        target_type: TargetType,
+        vote_type: VoteType,
     ) -> Result<VoteResponse> {
+        let voter = users::Entity::find_by_id(user_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        if voter.verified_at.is_none() {
+            return Err(AppError::Forbidden("Verify your email address before voting".to_string()));
+        }
+
+        let locked_at = match target_type {
+            TargetType::Question => questions::Entity::find_by_id(target_id)
+                .one(&self.db)
+                .await?
+                .map(|question| question.locked_at),
+            TargetType::Answer => answers::Entity::find_by_id(target_id)
+                .one(&self.db)
+                .await?
+                .map(|answer| answer.locked_at),
+        };
+
+        match locked_at {
+            None => return Err(AppError::NotFound(format!("The {} being voted on does not exist", target_type.as_str()))),
+            Some(Some(_)) => return Err(AppError::Forbidden(format!("This {} is locked and cannot be voted on", target_type.as_str()))),
+            Some(None) => {}
+        }
+
         let txn = self.db.begin().await?;
 
         let existing_vote = votes::Entity::find()
@@ -26,28 +56,29 @@ impl VoteRepository {
                 Condition::all()
                     .add(votes::Column::UserId.eq(user_id))
                     .add(votes::Column::TargetId.eq(target_id))
-                    .add(votes::Column::TargetType.eq(target_type.clone()))
+                    .add(votes::Column::TargetType.eq(target_type))
             )
             .one(&txn)
             .await?;
 
         match existing_vote {
             Some(existing) if existing.vote_type != vote_type => {
-                let old_vote_type = existing.vote_type.clone();
+                let old_vote_type = existing.vote_type;
 
                 let mut vote_model: votes::ActiveModel = existing.into();
-                vote_model.vote_type = Set(vote_type.clone());
+                vote_model.vote_type = Set(vote_type);
                 let updated = vote_model.update(&txn).await?;
 
                 let reputation_change = self.calculate_reputation_change(
-                    &old_vote_type,
-                    &vote_type,
-                    &target_type
+                    Some(old_vote_type),
+                    vote_type,
+                    target_type
                 );
 
-                self.update_content_owner_reputation(&txn, target_id, &target_type, reputation_change).await?;
+                self.update_content_owner_reputation(&txn, target_id, target_type, reputation_change).await?;
 
                 txn.commit().await?;
+                self.publish_vote_change(target_id, target_type).await?;
                 Ok(self.to_response(updated))
             },
             Some(existing) => {
@@ -58,27 +89,28 @@ impl VoteRepository {
                 let vote = votes::ActiveModel {
                     user_id: Set(user_id),
                     target_id: Set(target_id),
-                    target_type: Set(target_type.clone()),
-                    vote_type: Set(vote_type.clone()),
+                    target_type: Set(target_type),
+                    vote_type: Set(vote_type),
                     created_at: Set(chrono::Utc::now()),
                     ..Default::default()
                 }.insert(&txn).await?;
 
                 let reputation_change = self.calculate_reputation_change(
-                    "",
-                    &vote_type,
-                    &target_type
+                    None,
+                    vote_type,
+                    target_type
                 );
 
-                self.update_content_owner_reputation(&txn, target_id, &target_type, reputation_change).await?;
+                self.update_content_owner_reputation(&txn, target_id, target_type, reputation_change).await?;
 
                 txn.commit().await?;
+                self.publish_vote_change(target_id, target_type).await?;
                 Ok(self.to_response(vote))
             }
         }
     }
 
-    pub async fn get_vote_count(&self, target_id: i32, target_type: &str) -> Result<VoteCount> {
+    pub async fn get_vote_count(&self, target_id: i32, target_type: TargetType) -> Result<VoteCount> {
         let votes = votes::Entity::find()
             .filter(
                 Condition::all()
@@ -88,8 +120,8 @@ impl VoteRepository {
             .all(&self.db)
             .await?;
 
-        let upvotes = votes.iter().filter(|v| v.vote_type == "up").count() as i64;
-        let downvotes = votes.iter().filter(|v| v.vote_type == "down").count() as i64;
+        let upvotes = votes.iter().filter(|v| v.vote_type == VoteType::Up).count() as i64;
+        let downvotes = votes.iter().filter(|v| v.vote_type == VoteType::Down).count() as i64;
 
         Ok(VoteCount {
             upvotes,
@@ -98,7 +130,7 @@ impl VoteRepository {
         })
     }
 
-    pub async fn get_user_vote(&self, user_id: i32, target_id: i32, target_type: &str) -> Result<Option<String>> {
+    pub async fn get_user_vote(&self, user_id: i32, target_id: i32, target_type: TargetType) -> Result<Option<VoteType>> {
         let vote = votes::Entity::find()
             .filter(
                 Condition::all()
@@ -112,6 +144,30 @@ impl VoteRepository {
         Ok(vote.map(|v| v.vote_type))
     }
 
+    async fn publish_vote_change(&self, target_id: i32, target_type: TargetType) -> Result<()> {
+        let question_id = match target_type {
+            TargetType::Question => Some(target_id),
+            TargetType::Answer => answers::Entity::find_by_id(target_id)
+                .select_only()
+                .column(answers::Column::QuestionId)
+                .into_tuple::<i32>()
+                .one(&self.db)
+                .await?,
+        };
+
+        if let Some(question_id) = question_id {
+            let total = self.get_vote_count(target_id, target_type).await?.total;
+            self.events.publish(ActivityEvent::VoteChanged {
+                question_id,
+                target_id,
+                target_type,
+                total,
+            });
+        }
+
+        Ok(())
+    }
+
     fn to_response(&self, vote: votes::Model) -> VoteResponse {
         VoteResponse {
             id: vote.id,
@@ -123,27 +179,26 @@ impl VoteRepository {
         }
     }
 
-    fn calculate_reputation_change(&self, old_vote: &str, new_vote: &str, target_type: &str) -> i32 {
+    fn calculate_reputation_change(&self, old_vote: Option<VoteType>, new_vote: VoteType, target_type: TargetType) -> i32 {
         match target_type {
-            "question" => {
+            TargetType::Question => {
                 match (old_vote, new_vote) {
-                    ("", "up") => 5,
-                    ("", "down") => -2,
-                    ("up", "down") => -7,
-                    ("down", "up") => 7,
+                    (None, VoteType::Up) => 5,
+                    (None, VoteType::Down) => -2,
+                    (Some(VoteType::Up), VoteType::Down) => -7,
+                    (Some(VoteType::Down), VoteType::Up) => 7,
                     _ => 0,
                 }
             },
-            "answer" => {
+            TargetType::Answer => {
                 match (old_vote, new_vote) {
-                    ("", "up") => 10,
-                    ("", "down") => -2,
-                    ("up", "down") => -12,
-                    ("down", "up") => 12,
+                    (None, VoteType::Up) => 10,
+                    (None, VoteType::Down) => -2,
+                    (Some(VoteType::Up), VoteType::Down) => -12,
+                    (Some(VoteType::Down), VoteType::Up) => 12,
                     _ => 0,
                 }
             },
-            _ => 0,
         }
     }
 
@@ -151,30 +206,31 @@ impl VoteRepository {
         &self,
         txn: &DatabaseTransaction,
         target_id: i32,
-        target_type: &str,
+        target_type: TargetType,
         reputation_change: i32,
     ) -> Result<()> {
-        let owner_id = match target_type {
-            "question" => {
+        let owner = match target_type {
+            TargetType::Question => {
                 questions::Entity::find_by_id(target_id)
                     .select_only()
                     .column(questions::Column::UserId)
-                    .into_tuple::<i32>()
+                    .column(questions::Column::Id)
+                    .into_tuple::<(i32, i32)>()
                     .one(txn)
                     .await?
             },
-            "answer" => {
+            TargetType::Answer => {
                 answers::Entity::find_by_id(target_id)
                     .select_only()
                     .column(answers::Column::UserId)
-                    .into_tuple::<i32>()
+                    .column(answers::Column::QuestionId)
+                    .into_tuple::<(i32, i32)>()
                     .one(txn)
                     .await?
             },
-            _ => None,
         };
 
-        if let Some(owner_id) = owner_id {
+        if let Some((owner_id, question_id)) = owner {
             let user = users::Entity::find_by_id(owner_id)
                 .one(txn)
                 .await?
@@ -183,6 +239,15 @@ impl VoteRepository {
             let mut user_model: users::ActiveModel = user.into();
             user_model.reputation = Set(user_model.reputation.unwrap() + reputation_change);
             user_model.update(txn).await?;
+
+            NotificationRepository::record_reputation(
+                txn,
+                owner_id,
+                target_type.into(),
+                target_id,
+                question_id,
+                reputation_change,
+            ).await?;
         }
 
         Ok(())
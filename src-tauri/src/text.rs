@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "can", "do", "does", "for", "from", "get",
+    "how", "i", "if", "in", "into", "is", "it", "me", "my", "no", "not", "of", "on", "or", "so", "that",
+    "the", "there", "this", "to", "use", "using", "was", "what", "when", "where", "which", "while",
+    "why", "with", "without", "you",
+];
+
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || matches!(c, '+' | '#')))
+        .map(|word| word.to_lowercase())
+        .filter(|word| word.chars().any(char::is_alphanumeric) && !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+pub fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
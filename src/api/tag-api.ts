@@ -3,16 +3,29 @@ import { safeInvoke } from './tauri-utils';
 
 export interface CreateTagRequest {
   name: string;
-  description?: string;
+  excerpt?: string;
+  wiki?: string;
+}
+
+export interface TopAnswerer {
+  user: {
+    id: number;
+    username: string;
+    display_name: string;
+    reputation: number;
+    avatar_url?: string;
+  };
+  answer_count: number;
 }
 
 export interface TagResponse {
   id: number;
   name: string;
-  description?: string;
+  excerpt?: string;
   question_count: number;
-  created_at: string;
-  updated_at: string;
+  questions_this_week: number;
+  questions_this_month: number;
+  top_answerers: TopAnswerer[];
 }
 
 
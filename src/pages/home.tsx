@@ -269,8 +269,8 @@ export default function Home() {
                                         <h3 className="text-xl font-semibold text-indigo-700">{tag.name}</h3>
                                     </div>
 
-                                    {tag.description && (
-                                        <p className="text-gray-600 text-sm mb-4 line-clamp-2">{tag.description}</p>
+                                    {tag.excerpt && (
+                                        <p className="text-gray-600 text-sm mb-4 line-clamp-2">{tag.excerpt}</p>
                                     )}
 
                                     <div className="flex justify-between items-center">
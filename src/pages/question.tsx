@@ -136,7 +136,7 @@ export default function Questions({ onQuestionClick, onAskQuestion }: QuestionsP
         const search = tagSearch.toLowerCase();
         setFilteredTags(tags.filter(tag => 
           tag.name.toLowerCase().includes(search) || 
-          (tag.description && tag.description.toLowerCase().includes(search))
+          (tag.excerpt && tag.excerpt.toLowerCase().includes(search))
         ));
       }
     }
@@ -546,8 +546,8 @@ export default function Questions({ onQuestionClick, onAskQuestion }: QuestionsP
                       content={
                         <div className="p-2 max-w-xs">
                           <div className="font-medium text-indigo-800">{tag.name}</div>
-                          {tag.description && (
-                            <div className="text-xs text-slate-600 mt-1">{tag.description}</div>
+                          {tag.excerpt && (
+                            <div className="text-xs text-slate-600 mt-1">{tag.excerpt}</div>
                           )}
                           <div className="text-xs text-indigo-600 mt-1">
                             {tag.question_count} question{tag.question_count !== 1 ? 's' : ''}